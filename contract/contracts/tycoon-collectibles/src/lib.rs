@@ -325,9 +325,9 @@ impl TycoonCollectibles {
             emit_perk_activated_event(&env, &caller, token_id, perk.clone(), strength);
         }
 
-        // JailFree: Free from jail - emit event for future implementation
+        // JailFree: Free from jail - tycoon-main-game's use_jail_free_perk
+        // burns through this entry point and releases the player
         if matches!(perk, Perk::JailFree) {
-            // TODO: Implement jail free logic
             emit_perk_activated_event(&env, &caller, token_id, perk.clone(), strength);
         }
 
@@ -1,5 +1,5 @@
 #![allow(dead_code)]
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
 
 /// Emit a FundsWithdrawn events
 pub fn emit_funds_withdrawn(env: &Env, token: &Address, to: &Address, amount: u128) {
@@ -9,8 +9,64 @@ pub fn emit_funds_withdrawn(env: &Env, token: &Address, to: &Address, amount: u1
 }
 
 /// Emit a PlayerRemovedFromGame event
-pub fn emit_player_removed_from_game(env: &Env, game_id: u128, player: &Address, turn_count: u32) {
+pub fn emit_player_removed_from_game(
+    env: &Env,
+    game_id: u128,
+    player: &Address,
+    turn_count: u32,
+    refund: u128,
+) {
     let topics = (Symbol::new(env, "PlayerRemovedFromGame"), game_id, player);
     #[allow(deprecated)]
-    env.events().publish(topics, turn_count);
+    env.events().publish(topics, (turn_count, refund));
+}
+
+/// Emit a GameRegistered event
+pub fn emit_game_registered(env: &Env, game_id: u128, players: &Vec<Address>, stake: u128) {
+    let topics = (Symbol::new(env, "GameRegistered"), game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, (players.clone(), stake));
+}
+
+/// Emit a RegistrationVoucherIssued event
+pub fn emit_registration_voucher_issued(env: &Env, player: &Address, token_id: u128, amount: u128) {
+    let topics = (
+        Symbol::new(env, "RegistrationVoucherIssued"),
+        player,
+        token_id,
+    );
+    #[allow(deprecated)]
+    env.events().publish(topics, amount);
+}
+
+/// Emit a UsernameChanged event
+pub fn emit_username_changed(
+    env: &Env,
+    player: &Address,
+    old_username: &String,
+    new_username: &String,
+) {
+    let topics = (Symbol::new(env, "UsernameChanged"), player);
+    #[allow(deprecated)]
+    env.events()
+        .publish(topics, (old_username.clone(), new_username.clone()));
+}
+
+/// Emit a GameResultRecorded event
+pub fn emit_game_result_recorded(
+    env: &Env,
+    game_id: u128,
+    winner: &Address,
+    players: &Vec<Address>,
+) {
+    let topics = (Symbol::new(env, "GameResultRecorded"), game_id, winner);
+    #[allow(deprecated)]
+    env.events().publish(topics, players.clone());
+}
+
+/// Emit a GameSettled event
+pub fn emit_game_settled(env: &Env, game_id: u128, winner: &Address, payout: u128) {
+    let topics = (Symbol::new(env, "GameSettled"), game_id, winner);
+    #[allow(deprecated)]
+    env.events().publish(topics, payout);
 }
@@ -3,8 +3,17 @@
 mod events;
 mod storage;
 
-use soroban_sdk::{contract, contractimpl, token, Address, Env, IntoVal, String, Symbol};
-use storage::{get_backend_game_controller, get_owner, get_tyc_token, get_usdc_token, CollectibleInfo, User};
+use soroban_sdk::{contract, contractimpl, token, Address, Env, IntoVal, String, Symbol, Vec};
+use storage::{
+    get_backend_game_controller, get_owner, get_tyc_token, get_usdc_token, CollectibleInfo, Game,
+    RefundTier, User,
+};
+
+/// Maximum username length in bytes
+const MAX_USERNAME_LEN: u32 = 20;
+
+/// Basis points denominator for refund tiers
+const BPS_DENOMINATOR: u32 = 10_000;
 
 #[contract]
 pub struct TycoonContract;
@@ -44,10 +53,14 @@ impl TycoonContract {
             panic!("Invalid token address");
         }
 
-        // Create token client and check balance
+        // Create token client and check balance, excluding USDC escrowed for
+        // unsettled games
         let token_client = token::Client::new(&env, &token);
         let contract_address = env.current_contract_address();
-        let balance = token_client.balance(&contract_address);
+        let mut balance = token_client.balance(&contract_address);
+        if token == usdc_token {
+            balance -= storage::get_escrowed_usdc(&env) as i128;
+        }
 
         if balance < amount as i128 {
             panic!("Insufficient contract balance");
@@ -118,42 +131,241 @@ impl TycoonContract {
 
         // Validate username length (3-20 chars)
         let len = username.len();
-        if len < 3 || len > 20 {
+        if !(3..=20).contains(&len) {
             panic!("Username must be 3-20 characters");
         }
 
+        // Claim the username in the case-insensitive index
+        let key = Self::normalize_username(&env, &username);
+        if storage::is_username_reserved(&env, &key) {
+            panic!("Username is reserved");
+        }
+        if storage::get_username_owner(&env, &key).is_some() {
+            panic!("Username already taken");
+        }
+        storage::set_username_owner(&env, &key, &caller);
+
+        // Assign the next sequential player id
+        let id = storage::next_player_id(&env);
+        storage::set_player_by_id(&env, id, &caller);
+
         // Create user
-        let user = User {
-            id: env.ledger().sequence() as u64,
+        let mut user = User {
+            id,
             username: username.clone(),
             address: caller.clone(),
             registered_at: env.ledger().timestamp(),
             games_played: 0,
             games_won: 0,
+            voucher_issued: false,
+            username_changed_at: 0,
         };
 
+        // Mint the registration voucher in the same transaction if enabled
+        if storage::get_auto_mint_voucher(&env) {
+            Self::issue_registration_voucher(&env, &mut user);
+        }
+
         // Store user and mark as registered
         storage::set_user(&env, &caller, &user);
         storage::set_registered(&env, &caller);
     }
 
+    /// Change the caller's username, keeping their stats.
+    ///
+    /// Frees the old name in the username index, enforces the cooldown since
+    /// the last change and charges the configured TYC fee, which is paid into
+    /// this contract (withdrawable by the owner via `withdraw_funds`).
+    pub fn change_username(env: Env, caller: Address, new_username: String) {
+        caller.require_auth();
+
+        let mut user = storage::get_user(&env, &caller).expect("Player not registered");
+
+        // Validate username length (3-20 chars)
+        let len = new_username.len();
+        if !(3..=20).contains(&len) {
+            panic!("Username must be 3-20 characters");
+        }
+
+        if new_username == user.username {
+            panic!("Username unchanged");
+        }
+
+        // Enforce cooldown since the last change
+        let now = env.ledger().timestamp();
+        let cooldown = storage::get_username_change_cooldown(&env);
+        if user.username_changed_at != 0 && now < user.username_changed_at.saturating_add(cooldown)
+        {
+            panic!("Username change on cooldown");
+        }
+
+        // Move the username index entry; a case-only change keeps the same key
+        let old_key = Self::normalize_username(&env, &user.username);
+        let new_key = Self::normalize_username(&env, &new_username);
+        if new_key != old_key {
+            if storage::is_username_reserved(&env, &new_key) {
+                panic!("Username is reserved");
+            }
+            if storage::get_username_owner(&env, &new_key).is_some() {
+                panic!("Username already taken");
+            }
+            // A pre-upgrade name may be indexed to someone else; leave it
+            if storage::get_username_owner(&env, &old_key) == Some(caller.clone()) {
+                storage::remove_username_owner(&env, &old_key);
+            }
+            storage::set_username_owner(&env, &new_key, &caller);
+        }
+
+        // Charge the rename fee in TYC
+        let fee = storage::get_username_change_fee(&env);
+        if fee > 0 {
+            let tyc_client = token::Client::new(&env, &get_tyc_token(&env));
+            let contract_address = env.current_contract_address();
+            tyc_client.transfer(&caller, &contract_address, &(fee as i128));
+        }
+
+        let old_username = user.username.clone();
+        user.username = new_username.clone();
+        user.username_changed_at = now;
+        storage::set_user(&env, &caller, &user);
+
+        events::emit_username_changed(&env, &caller, &old_username, &new_username);
+    }
+
+    /// Configure the username change fee (in TYC) and cooldown in seconds (owner only)
+    pub fn set_username_change_config(env: Env, fee: u128, cooldown: u64) {
+        let owner = get_owner(&env);
+        owner.require_auth();
+
+        storage::set_username_change_fee(&env, fee);
+        storage::set_username_change_cooldown(&env, cooldown);
+    }
+
+    pub fn get_username_change_config(env: Env) -> (u128, u64) {
+        (
+            storage::get_username_change_fee(&env),
+            storage::get_username_change_cooldown(&env),
+        )
+    }
+
+    /// Mint `player`'s registration voucher (owner only). Requires this
+    /// contract to be the reward system's backend minter; see
+    /// `set_registration_voucher`.
     pub fn mint_registration_voucher(env: Env, player: Address) {
         let owner = get_owner(&env);
         owner.require_auth();
 
-        // Mint 2 TYC voucher via reward system
-        let reward_system = storage::get_reward_system(&env);
-        let _token_id: u128 = env.invoke_contract(
-            &reward_system,
-            &Symbol::new(&env, "mint_voucher"),
-            soroban_sdk::vec![&env, player.into_val(&env), 2_0000000u128.into_val(&env)],
-        );
+        let mut user = storage::get_user(&env, &player).expect("Player not registered");
+
+        Self::issue_registration_voucher(&env, &mut user);
+        storage::set_user(&env, &player, &user);
+    }
+
+    /// Configure the registration voucher (owner only).
+    ///
+    /// `amount` is the TYC value minted per voucher. When `auto_mint` is set,
+    /// `register_player` mints the voucher in the same transaction.
+    ///
+    /// Deployment constraint: vouchers are minted with this contract as the
+    /// minter, and the reward system's `mint_voucher` accepts only its admin
+    /// or its single backend minter. This contract must be that minter, which
+    /// displaces the current one (tycoon-main-game or a backend service).
+    /// Enable registration vouchers here or in tycoon-main-game, not both.
+    pub fn set_registration_voucher(env: Env, amount: u128, auto_mint: bool) {
+        let owner = get_owner(&env);
+        owner.require_auth();
+
+        storage::set_registration_voucher_amount(&env, amount);
+        storage::set_auto_mint_voucher(&env, auto_mint);
+    }
+
+    pub fn get_registration_voucher(env: Env) -> (u128, bool) {
+        (
+            storage::get_registration_voucher_amount(&env),
+            storage::get_auto_mint_voucher(&env),
+        )
     }
 
     pub fn get_user(env: Env, address: Address) -> Option<User> {
         storage::get_user(&env, &address)
     }
 
+    pub fn get_user_by_id(env: Env, id: u64) -> Option<User> {
+        let address = storage::get_player_by_id(&env, id)?;
+        storage::get_user(&env, &address)
+    }
+
+    pub fn get_total_players(env: Env) -> u64 {
+        storage::get_player_count(&env)
+    }
+
+    /// Look up the address registered under `username` (case-insensitive)
+    pub fn get_address_by_username(env: Env, username: String) -> Option<Address> {
+        if username.len() > MAX_USERNAME_LEN {
+            return None;
+        }
+        let key = Self::normalize_username(&env, &username);
+        storage::get_username_owner(&env, &key)
+    }
+
+    /// Add the usernames of players registered before the username index
+    /// existed to it (owner only), so their names cannot be registered again.
+    /// A name already claimed by another player (a case-insensitive
+    /// duplicate) stays with its current holder.
+    pub fn backfill_username_index(env: Env, players: Vec<Address>) {
+        let owner = get_owner(&env);
+        owner.require_auth();
+
+        for player in players.iter() {
+            let user = storage::get_user(&env, &player).expect("Player not registered");
+            let key = Self::normalize_username(&env, &user.username);
+            if storage::get_username_owner(&env, &key).is_none() {
+                storage::set_username_owner(&env, &key, &player);
+            }
+        }
+    }
+
+    /// Give players registered before sequential ids existed a counter id
+    /// (owner only), replacing their ledger-sequence id, so they can be found
+    /// with `get_user_by_id` and are counted by `get_total_players`. Players
+    /// whose id already maps back to them are skipped.
+    pub fn backfill_player_ids(env: Env, players: Vec<Address>) {
+        let owner = get_owner(&env);
+        owner.require_auth();
+
+        for player in players.iter() {
+            let mut user = storage::get_user(&env, &player).expect("Player not registered");
+            if storage::get_player_by_id(&env, user.id) == Some(player.clone()) {
+                continue;
+            }
+
+            user.id = storage::next_player_id(&env);
+            storage::set_player_by_id(&env, user.id, &player);
+            storage::set_user(&env, &player, &user);
+        }
+    }
+
+    /// Reserve or release a username so players cannot register it (owner only).
+    /// Matching is case-insensitive. Existing holders of the name are unaffected.
+    pub fn set_username_reserved(env: Env, username: String, reserved: bool) {
+        let owner = get_owner(&env);
+        owner.require_auth();
+
+        if username.len() > MAX_USERNAME_LEN {
+            panic!("Username must be 3-20 characters");
+        }
+        let key = Self::normalize_username(&env, &username);
+        storage::set_username_reserved(&env, &key, reserved);
+    }
+
+    pub fn is_username_reserved(env: Env, username: String) -> bool {
+        if username.len() > MAX_USERNAME_LEN {
+            return false;
+        }
+        let key = Self::normalize_username(&env, &username);
+        storage::is_username_reserved(&env, &key)
+    }
+
     pub fn set_backend_game_controller(env: Env, new_controller: Address) {
         let owner = get_owner(&env);
         owner.require_auth();
@@ -161,29 +373,299 @@ impl TycoonContract {
         storage::set_backend_game_controller(&env, &new_controller);
     }
 
-    pub fn remove_player_from_game(env: Env, caller: Address, game_id: u128, player: Address, turn_count: u32) {
-        // Require authentication from the caller
+    /// Record a finished game's participants and winner in their stats.
+    /// Only the backend game controller may call this. Each game ID is
+    /// applied at most once; repeated calls return `false` and change nothing.
+    pub fn record_game_result(
+        env: Env,
+        caller: Address,
+        game_id: u128,
+        players: Vec<Address>,
+        winner: Address,
+    ) -> bool {
         caller.require_auth();
 
-        // Get owner and backend controller
+        let is_backend_controller =
+            get_backend_game_controller(&env).is_some_and(|controller| caller == controller);
+        if !is_backend_controller {
+            panic!("Unauthorized: caller must be backend game controller");
+        }
+
+        if storage::is_game_result_recorded(&env, game_id) {
+            return false;
+        }
+
+        if !players.contains(&winner) {
+            panic!("Winner is not a game participant");
+        }
+
+        // Validate every participant before touching any stats
+        let mut users: Vec<User> = Vec::new(&env);
+        for (i, player) in players.iter().enumerate() {
+            if players.first_index_of(&player) != Some(i as u32) {
+                panic!("Duplicate player");
+            }
+            let user = storage::get_user(&env, &player).expect("Player not registered");
+            users.push_back(user);
+        }
+
+        for mut user in users.iter() {
+            user.games_played += 1;
+            if user.address == winner {
+                user.games_won += 1;
+            }
+            storage::set_user(&env, &user.address, &user);
+        }
+
+        storage::set_game_result_recorded(&env, game_id);
+
+        events::emit_game_result_recorded(&env, game_id, &winner, &players);
+
+        true
+    }
+
+    pub fn is_game_result_recorded(env: Env, game_id: u128) -> bool {
+        storage::is_game_result_recorded(&env, game_id)
+    }
+
+    /// Register a game and escrow `stake_per_player` USDC from each player.
+    /// Only the owner or backend game controller may register games, and each
+    /// player must authorize their stake.
+    pub fn register_game(
+        env: Env,
+        caller: Address,
+        game_id: u128,
+        players: Vec<Address>,
+        stake_per_player: u128,
+    ) {
+        caller.require_auth();
+        Self::require_owner_or_controller(&env, &caller);
+
+        if storage::get_game(&env, game_id).is_some() {
+            panic!("Game already exists");
+        }
+        if players.is_empty() {
+            panic!("Game has no players");
+        }
+
+        for (i, player) in players.iter().enumerate() {
+            if players.first_index_of(&player) != Some(i as u32) {
+                panic!("Duplicate player");
+            }
+        }
+
+        if stake_per_player > 0 {
+            if stake_per_player > i128::MAX as u128 {
+                panic!("Stake amount too large");
+            }
+            let usdc_client = token::Client::new(&env, &get_usdc_token(&env));
+            let contract_address = env.current_contract_address();
+            for player in players.iter() {
+                player.require_auth();
+                usdc_client.transfer(&player, &contract_address, &(stake_per_player as i128));
+            }
+        }
+
+        let escrow = stake_per_player
+            .checked_mul(players.len() as u128)
+            .expect("Stake amount too large");
+        let game = Game {
+            id: game_id,
+            players: players.clone(),
+            removed: Vec::new(&env),
+            stake_per_player,
+            escrow,
+            settled: false,
+        };
+        storage::set_game(&env, &game);
+        storage::set_escrowed_usdc(&env, storage::get_escrowed_usdc(&env) + escrow);
+
+        events::emit_game_registered(&env, game_id, &players, stake_per_player);
+    }
+
+    pub fn get_game(env: Env, game_id: u128) -> Option<Game> {
+        storage::get_game(&env, game_id)
+    }
+
+    /// Set the refund schedule applied by `remove_player_from_game`.
+    /// Tiers must have strictly increasing `max_turn` and at most 10000 bps;
+    /// a removal after the last tier forfeits the whole stake.
+    pub fn set_refund_schedule(env: Env, schedule: Vec<RefundTier>) {
         let owner = get_owner(&env);
-        let backend_controller = get_backend_game_controller(&env);
+        owner.require_auth();
+
+        let mut previous: Option<u32> = None;
+        for tier in schedule.iter() {
+            if tier.refund_bps > BPS_DENOMINATOR {
+                panic!("Refund exceeds 10000 bps");
+            }
+            if previous.is_some_and(|max_turn| tier.max_turn <= max_turn) {
+                panic!("Refund tiers must be in increasing turn order");
+            }
+            previous = Some(tier.max_turn);
+        }
+
+        storage::set_refund_schedule(&env, &schedule);
+    }
+
+    pub fn get_refund_schedule(env: Env) -> Vec<RefundTier> {
+        storage::get_refund_schedule(&env)
+    }
+
+    /// Remove `player` from a registered game, refunding part of their stake
+    /// according to the refund schedule for `turn_count`. The remainder is
+    /// forfeited to the pot and stays in escrow for the winner, unless no
+    /// players remain to win it, in which case the game's escrow is released
+    /// to the contract.
+    pub fn remove_player_from_game(
+        env: Env,
+        caller: Address,
+        game_id: u128,
+        player: Address,
+        turn_count: u32,
+    ) {
+        // Require authentication from the caller
+        caller.require_auth();
 
         // Check authorization: caller must be owner OR backend controller
-        let is_owner = caller == owner;
-        let is_backend_controller = backend_controller.map_or(false, |controller| caller == controller);
+        Self::require_owner_or_controller(&env, &caller);
+
+        let mut game = storage::get_game(&env, game_id).expect("Game not found");
+
+        if game.settled {
+            panic!("Game already settled");
+        }
+        if game.removed.contains(&player) {
+            panic!("Player already removed");
+        }
+        let index = game
+            .players
+            .first_index_of(&player)
+            .expect("Player not in game");
+
+        let refund_bps = Self::refund_bps_for_turn(&env, turn_count);
+        let refund = Self::apply_bps(game.stake_per_player, refund_bps);
+
+        // Update game state before paying out; only the refund leaves escrow
+        game.players.remove(index);
+        game.removed.push_back(player.clone());
+        game.escrow -= refund;
+        let mut released = refund;
+        if game.players.is_empty() {
+            released += game.escrow;
+            game.escrow = 0;
+            game.settled = true;
+        }
+        storage::set_game(&env, &game);
+        storage::set_escrowed_usdc(&env, storage::get_escrowed_usdc(&env) - released);
+
+        if refund > 0 {
+            let usdc_client = token::Client::new(&env, &get_usdc_token(&env));
+            let contract_address = env.current_contract_address();
+            usdc_client.transfer(&contract_address, &player, &(refund as i128));
+        }
+
+        // Emit event
+        events::emit_player_removed_from_game(&env, game_id, &player, turn_count, refund);
+    }
+
+    /// Pay a registered game's remaining escrow to `winner`, who must still
+    /// be in the game. Only the owner or backend game controller may settle,
+    /// and each game settles once.
+    pub fn settle_game(env: Env, caller: Address, game_id: u128, winner: Address) {
+        caller.require_auth();
+        Self::require_owner_or_controller(&env, &caller);
+
+        let mut game = storage::get_game(&env, game_id).expect("Game not found");
+        if game.settled {
+            panic!("Game already settled");
+        }
+        if !game.players.contains(&winner) {
+            panic!("Winner is not in game");
+        }
+
+        let payout = game.escrow;
+        game.escrow = 0;
+        game.settled = true;
+        storage::set_game(&env, &game);
+        storage::set_escrowed_usdc(&env, storage::get_escrowed_usdc(&env) - payout);
+
+        if payout > 0 {
+            let usdc_client = token::Client::new(&env, &get_usdc_token(&env));
+            let contract_address = env.current_contract_address();
+            usdc_client.transfer(&contract_address, &winner, &(payout as i128));
+        }
+
+        events::emit_game_settled(&env, game_id, &winner, payout);
+    }
+
+    /// Total USDC held in escrow for unsettled games
+    pub fn get_escrowed_usdc(env: Env) -> u128 {
+        storage::get_escrowed_usdc(&env)
+    }
+}
+
+impl TycoonContract {
+    fn require_owner_or_controller(env: &Env, caller: &Address) {
+        let is_owner = *caller == get_owner(env);
+        let is_backend_controller =
+            get_backend_game_controller(env).is_some_and(|controller| *caller == controller);
 
         if !is_owner && !is_backend_controller {
             panic!("Unauthorized: caller must be owner or backend game controller");
         }
+    }
 
-        // Stub implementation - no payout logic yet
-        // Future: Calculate payout based on turn_count and game state
-        // Future: Transfer tokens to player
-        // Future: Update game state
+    /// Refund share for a removal at `turn_count`: the first tier whose
+    /// `max_turn` covers it, or 0 once past the last tier.
+    fn refund_bps_for_turn(env: &Env, turn_count: u32) -> u32 {
+        storage::get_refund_schedule(env)
+            .iter()
+            .find(|tier| turn_count <= tier.max_turn)
+            .map_or(0, |tier| tier.refund_bps)
+    }
 
-        // Emit event
-        events::emit_player_removed_from_game(&env, game_id, &player, turn_count);
+    /// `amount * bps / 10000`, rounded down, without overflowing.
+    fn apply_bps(amount: u128, bps: u32) -> u128 {
+        let denominator = BPS_DENOMINATOR as u128;
+        let bps = bps as u128;
+        (amount / denominator) * bps + (amount % denominator) * bps / denominator
+    }
+
+    /// Lowercase the ASCII letters of a username to build its index key.
+    /// Callers must ensure the username is at most `MAX_USERNAME_LEN` bytes.
+    fn normalize_username(env: &Env, username: &String) -> String {
+        let len = username.len() as usize;
+        let mut buf = [0u8; MAX_USERNAME_LEN as usize];
+        username.copy_into_slice(&mut buf[..len]);
+        buf[..len].make_ascii_lowercase();
+        String::from_bytes(env, &buf[..len])
+    }
+
+    /// Mint the registration voucher to `user` via the reward system, with
+    /// this contract as the minter, and flag it as issued on the user.
+    fn issue_registration_voucher(env: &Env, user: &mut User) {
+        if user.voucher_issued {
+            panic!("Voucher already issued");
+        }
+
+        let amount = storage::get_registration_voucher_amount(env);
+        let reward_system = storage::get_reward_system(env);
+        let minter = env.current_contract_address();
+        let token_id: u128 = env.invoke_contract(
+            &reward_system,
+            &Symbol::new(env, "mint_voucher"),
+            soroban_sdk::vec![
+                env,
+                minter.into_val(env),
+                user.address.into_val(env),
+                amount.into_val(env),
+            ],
+        );
+
+        user.voucher_issued = true;
+
+        events::emit_registration_voucher_issued(env, &user.address, token_id, amount);
     }
 }
 
@@ -1,5 +1,5 @@
 #![allow(dead_code)]
-use soroban_sdk::{contracttype, Address, Env, String};
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
 
 /// Storage keys for the contract
 #[derive(Clone)]
@@ -11,10 +11,23 @@ pub enum DataKey {
     IsInitialized,
     Collectible(u128), // token_id -> CollectibleInfo
     CashTier(u32),     // tier -> value
-    User(Address),     // address -> User
+    User(Address),     // address -> LegacyUser, records written before UserV2
     Registered(Address), // address -> bool
     RewardSystem,      // reward system contract address
     BackendGameController, // backend game controller address
+    RegistrationVoucherAmount, // TYC value of the registration voucher
+    AutoMintVoucher,       // mint the voucher during register_player
+    UsernameOwner(String), // lowercased username -> address
+    ReservedUsername(String), // lowercased username -> bool
+    UsernameChangeFee,     // TYC fee charged by change_username
+    UsernameChangeCooldown, // seconds between username changes
+    PlayerCount,           // total registered players (last assigned id)
+    PlayerById(u64),       // player id -> address
+    GameResultRecorded(u128), // game_id -> bool, guards stats updates
+    Game(u128),            // game_id -> Game
+    RefundSchedule,        // Vec<RefundTier> used by remove_player_from_game
+    EscrowedUsdc,          // USDC held for unsettled games, excluded from withdraw_funds
+    UserV2(Address),       // address -> User
 }
 
 /// Information about a collectible NFT
@@ -38,8 +51,50 @@ pub struct User {
     pub registered_at: u64,
     pub games_played: u32,
     pub games_won: u32,
+    pub voucher_issued: bool,
+    pub username_changed_at: u64, // 0 if the username was never changed
 }
 
+/// User record as stored under `DataKey::User` before the voucher and
+/// rename fields were added. Read-only: `set_user` always writes `User`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct LegacyUser {
+    pub id: u64,
+    pub username: String,
+    pub address: Address,
+    pub registered_at: u64,
+    pub games_played: u32,
+    pub games_won: u32,
+}
+
+/// A game whose stakes are escrowed by this contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Game {
+    pub id: u128,
+    pub players: Vec<Address>,  // players still in the game
+    pub removed: Vec<Address>,  // players removed via remove_player_from_game
+    pub stake_per_player: u128, // USDC escrowed per player
+    pub escrow: u128,           // USDC still held for this game
+    pub settled: bool,          // escrow paid out via settle_game
+}
+
+/// Refund tier: a player removed at or before `max_turn` gets `refund_bps`
+/// of their stake back; the rest is forfeited to the contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RefundTier {
+    pub max_turn: u32,
+    pub refund_bps: u32,
+}
+
+/// Default registration voucher value: 2 TYC (7 decimals)
+pub const DEFAULT_REGISTRATION_VOUCHER_AMOUNT: u128 = 2_0000000;
+
+/// Default cooldown between username changes: 30 days
+pub const DEFAULT_USERNAME_CHANGE_COOLDOWN: u64 = 30 * 24 * 60 * 60;
+
 /// Get the owner address from storage
 pub fn get_owner(env: &Env) -> Address {
     env.storage().instance().get(&DataKey::Owner).unwrap()
@@ -139,18 +194,42 @@ pub fn set_registered(env: &Env, address: &Address) {
         .set(&DataKey::Registered(address.clone()), &true);
 }
 
-/// Get user by address
+/// Get user by address, upgrading a pre-upgrade `LegacyUser` record.
+/// Legacy users never received a voucher (minting did not work before
+/// the upgrade) and never changed their username.
 pub fn get_user(env: &Env, address: &Address) -> Option<User> {
-    env.storage()
+    if let Some(user) = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UserV2(address.clone()))
+    {
+        return Some(user);
+    }
+
+    let legacy: LegacyUser = env
+        .storage()
         .persistent()
-        .get(&DataKey::User(address.clone()))
+        .get(&DataKey::User(address.clone()))?;
+    Some(User {
+        id: legacy.id,
+        username: legacy.username,
+        address: legacy.address,
+        registered_at: legacy.registered_at,
+        games_played: legacy.games_played,
+        games_won: legacy.games_won,
+        voucher_issued: false,
+        username_changed_at: 0,
+    })
 }
 
-/// Set user data
+/// Set user data, dropping any legacy record it replaces
 pub fn set_user(env: &Env, address: &Address, user: &User) {
     env.storage()
         .persistent()
-        .set(&DataKey::User(address.clone()), user);
+        .set(&DataKey::UserV2(address.clone()), user);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::User(address.clone()));
 }
 
 /// Get backend game controller address
@@ -162,3 +241,184 @@ pub fn get_backend_game_controller(env: &Env) -> Option<Address> {
 pub fn set_backend_game_controller(env: &Env, address: &Address) {
     env.storage().instance().set(&DataKey::BackendGameController, address);
 }
+
+/// Get the registration voucher amount (defaults to 2 TYC)
+pub fn get_registration_voucher_amount(env: &Env) -> u128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::RegistrationVoucherAmount)
+        .unwrap_or(DEFAULT_REGISTRATION_VOUCHER_AMOUNT)
+}
+
+/// Set the registration voucher amount
+pub fn set_registration_voucher_amount(env: &Env, amount: u128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RegistrationVoucherAmount, &amount);
+}
+
+/// Check if register_player should mint the voucher (defaults to false)
+pub fn get_auto_mint_voucher(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::AutoMintVoucher)
+        .unwrap_or(false)
+}
+
+/// Set whether register_player mints the voucher
+pub fn set_auto_mint_voucher(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AutoMintVoucher, &enabled);
+}
+
+/// Get the address owning a lowercased username
+pub fn get_username_owner(env: &Env, username: &String) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::UsernameOwner(username.clone()))
+}
+
+/// Set the address owning a lowercased username
+pub fn set_username_owner(env: &Env, username: &String, address: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::UsernameOwner(username.clone()), address);
+}
+
+/// Remove the owner of a lowercased username, freeing it
+pub fn remove_username_owner(env: &Env, username: &String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::UsernameOwner(username.clone()));
+}
+
+/// Check if a lowercased username is reserved
+pub fn is_username_reserved(env: &Env, username: &String) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReservedUsername(username.clone()))
+        .unwrap_or(false)
+}
+
+/// Reserve or release a lowercased username
+pub fn set_username_reserved(env: &Env, username: &String, reserved: bool) {
+    let key = DataKey::ReservedUsername(username.clone());
+    if reserved {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Get the TYC fee for changing username (defaults to 0)
+pub fn get_username_change_fee(env: &Env) -> u128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::UsernameChangeFee)
+        .unwrap_or(0)
+}
+
+/// Set the TYC fee for changing username
+pub fn set_username_change_fee(env: &Env, fee: u128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::UsernameChangeFee, &fee);
+}
+
+/// Get the cooldown between username changes (defaults to 30 days)
+pub fn get_username_change_cooldown(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::UsernameChangeCooldown)
+        .unwrap_or(DEFAULT_USERNAME_CHANGE_COOLDOWN)
+}
+
+/// Set the cooldown between username changes
+pub fn set_username_change_cooldown(env: &Env, cooldown: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::UsernameChangeCooldown, &cooldown);
+}
+
+/// Increment the player counter and return the new player id, starting at 1
+pub fn next_player_id(env: &Env) -> u64 {
+    let id = get_player_count(env) + 1;
+    env.storage().instance().set(&DataKey::PlayerCount, &id);
+    id
+}
+
+/// Get the total number of registered players
+pub fn get_player_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PlayerCount)
+        .unwrap_or(0)
+}
+
+/// Get the address registered under a player id
+pub fn get_player_by_id(env: &Env, id: u64) -> Option<Address> {
+    env.storage().persistent().get(&DataKey::PlayerById(id))
+}
+
+/// Set the address registered under a player id
+pub fn set_player_by_id(env: &Env, id: u64, address: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PlayerById(id), address);
+}
+
+/// Check if a game's result has already been applied to player stats
+pub fn is_game_result_recorded(env: &Env, game_id: u128) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::GameResultRecorded(game_id))
+        .unwrap_or(false)
+}
+
+/// Mark a game's result as applied to player stats
+pub fn set_game_result_recorded(env: &Env, game_id: u128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::GameResultRecorded(game_id), &true);
+}
+
+/// Get a game by id
+pub fn get_game(env: &Env, game_id: u128) -> Option<Game> {
+    env.storage().persistent().get(&DataKey::Game(game_id))
+}
+
+/// Set a game
+pub fn set_game(env: &Env, game: &Game) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Game(game.id), game);
+}
+
+/// Get the refund schedule (defaults to empty, i.e. full forfeiture)
+pub fn get_refund_schedule(env: &Env) -> Vec<RefundTier> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RefundSchedule)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the refund schedule
+pub fn set_refund_schedule(env: &Env, schedule: &Vec<RefundTier>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RefundSchedule, schedule);
+}
+
+/// Get the total USDC held in escrow across unsettled games
+pub fn get_escrowed_usdc(env: &Env) -> u128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::EscrowedUsdc)
+        .unwrap_or(0)
+}
+
+/// Set the total USDC held in escrow across unsettled games
+pub fn set_escrowed_usdc(env: &Env, amount: u128) {
+    env.storage().instance().set(&DataKey::EscrowedUsdc, &amount);
+}
@@ -2,9 +2,9 @@
 
 use super::*;
 use soroban_sdk::{
-    testutils::{Address as _, Events},
+    testutils::{Address as _, Events, Ledger},
     token::{StellarAssetClient, TokenClient},
-    Address, Env, String,
+    vec, Address, Env, String, Vec,
 };
 
 // Helper function to create a mock token contract
@@ -30,6 +30,65 @@ fn setup_contract(env: &Env) -> (Address, TycoonContractClient<'_>, Address, Add
     (contract_id, client, owner, tyc_token, usdc_token)
 }
 
+// Minimal stand-in for the reward system's mint_voucher entry point
+#[soroban_sdk::contract]
+pub struct MockRewardSystem;
+
+#[soroban_sdk::contractimpl]
+impl MockRewardSystem {
+    pub fn mint_voucher(env: Env, caller: Address, to: Address, tyc_value: u128) -> u128 {
+        caller.require_auth();
+        let count: u32 = env.storage().persistent().get(&to).unwrap_or(0);
+        env.storage().persistent().set(&to, &(count + 1));
+        env.storage().instance().set(&caller, &tyc_value);
+        1_000_000_000
+    }
+
+    pub fn minted_count(env: Env, to: Address) -> u32 {
+        env.storage().persistent().get(&to).unwrap_or(0)
+    }
+
+    pub fn last_value(env: Env, minter: Address) -> u128 {
+        env.storage().instance().get(&minter).unwrap_or(0)
+    }
+}
+
+// Helper function to setup and initialize a contract wired to a mock reward system
+fn setup_with_reward_system(
+    env: &Env,
+) -> (
+    Address,
+    TycoonContractClient<'_>,
+    MockRewardSystemClient<'_>,
+) {
+    let (contract_id, client, owner, tyc_token, usdc_token) = setup_contract(env);
+    let reward_system = env.register(MockRewardSystem, ());
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+    (
+        contract_id,
+        client,
+        MockRewardSystemClient::new(env, &reward_system),
+    )
+}
+
+// Write a user record in the pre-upgrade layout, as deployed data would hold it
+fn store_legacy_user(env: &Env, contract_id: &Address, player: &Address, username: &str) {
+    let legacy = storage::LegacyUser {
+        id: 7,
+        username: String::from_str(env, username),
+        address: player.clone(),
+        registered_at: 100,
+        games_played: 3,
+        games_won: 1,
+    };
+    env.as_contract(contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&storage::DataKey::User(player.clone()), &legacy);
+        storage::set_registered(env, player);
+    });
+}
+
 // ===== INITIALIZATION TESTS =====
 
 #[test]
@@ -394,6 +453,25 @@ fn test_register_player_username_too_long() {
 
 // ===== BACKEND GAME CONTROLLER TESTS =====
 
+// Mint the stake to each player and register the game through `caller`
+fn register_staked_game(
+    env: &Env,
+    client: &TycoonContractClient<'_>,
+    caller: &Address,
+    usdc_token: &Address,
+    game_id: u128,
+    players: &Vec<Address>,
+    stake: u128,
+) {
+    if stake > 0 {
+        let usdc_admin = StellarAssetClient::new(env, usdc_token);
+        for player in players.iter() {
+            usdc_admin.mint(&player, &(stake as i128));
+        }
+    }
+    client.register_game(caller, &game_id, players, &stake);
+}
+
 #[test]
 fn test_set_backend_game_controller_by_owner() {
     let env = Env::default();
@@ -409,6 +487,16 @@ fn test_set_backend_game_controller_by_owner() {
 
     // Verify by using the backend controller to remove a player
     let player = Address::generate(&env);
+    let players = vec![&env, player.clone()];
+    register_staked_game(
+        &env,
+        &client,
+        &backend_controller,
+        &usdc_token,
+        1,
+        &players,
+        0,
+    );
     client.remove_player_from_game(&backend_controller, &1, &player, &10);
 }
 
@@ -425,6 +513,8 @@ fn test_remove_player_from_game_by_owner() {
     let player = Address::generate(&env);
     let game_id = 1;
     let turn_count = 5;
+    let players = vec![&env, player.clone()];
+    register_staked_game(&env, &client, &owner, &usdc_token, game_id, &players, 100);
 
     client.remove_player_from_game(&owner, &game_id, &player, &turn_count);
 
@@ -449,6 +539,16 @@ fn test_remove_player_from_game_by_backend_controller() {
     let player = Address::generate(&env);
     let game_id = 2;
     let turn_count = 15;
+    let players = vec![&env, player.clone()];
+    register_staked_game(
+        &env,
+        &client,
+        &backend_controller,
+        &usdc_token,
+        game_id,
+        &players,
+        100,
+    );
 
     client.remove_player_from_game(&backend_controller, &game_id, &player, &turn_count);
 
@@ -509,6 +609,8 @@ fn test_remove_player_emits_correct_event() {
     let player = Address::generate(&env);
     let game_id = 42;
     let turn_count = 100;
+    let players = vec![&env, player.clone()];
+    register_staked_game(&env, &client, &owner, &usdc_token, game_id, &players, 100);
 
     client.remove_player_from_game(&owner, &game_id, &player, &turn_count);
 
@@ -541,6 +643,28 @@ fn test_backend_controller_integration() {
     client.register_player(&String::from_str(&env, "player1"), &player1);
     client.register_player(&String::from_str(&env, "player2"), &player2);
 
+    // Backend registers the games
+    let game1_players = vec![&env, player1.clone(), player2.clone()];
+    register_staked_game(
+        &env,
+        &client,
+        &backend_controller,
+        &usdc_token,
+        1,
+        &game1_players,
+        100,
+    );
+    let game2_players = vec![&env, player1.clone()];
+    register_staked_game(
+        &env,
+        &client,
+        &backend_controller,
+        &usdc_token,
+        2,
+        &game2_players,
+        100,
+    );
+
     // Backend removes players from games
     client.remove_player_from_game(&backend_controller, &1, &player1, &5);
     client.remove_player_from_game(&backend_controller, &1, &player2, &8);
@@ -552,3 +676,1014 @@ fn test_backend_controller_integration() {
     let events = env.events().all();
     assert!(!events.is_empty());
 }
+
+// ===== REGISTRATION VOUCHER TESTS =====
+
+#[test]
+fn test_mint_registration_voucher_uses_contract_as_minter() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, rewards) = setup_with_reward_system(&env);
+
+    let player = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "player1"), &player);
+    client.mint_registration_voucher(&player);
+
+    assert_eq!(rewards.minted_count(&player), 1);
+    assert_eq!(rewards.last_value(&contract_id), 2_0000000);
+    assert!(client.get_user(&player).unwrap().voucher_issued);
+}
+
+#[test]
+#[should_panic(expected = "Voucher already issued")]
+fn test_mint_registration_voucher_twice_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, _) = setup_with_reward_system(&env);
+
+    let player = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "player1"), &player);
+    client.mint_registration_voucher(&player);
+    client.mint_registration_voucher(&player);
+}
+
+#[test]
+#[should_panic(expected = "Player not registered")]
+fn test_mint_registration_voucher_unregistered_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, _) = setup_with_reward_system(&env);
+
+    client.mint_registration_voucher(&Address::generate(&env));
+}
+
+#[test]
+fn test_register_player_auto_mints_configured_voucher() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, rewards) = setup_with_reward_system(&env);
+
+    client.set_registration_voucher(&5_0000000, &true);
+    assert_eq!(client.get_registration_voucher(), (5_0000000, true));
+
+    let player = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "player1"), &player);
+
+    assert_eq!(rewards.minted_count(&player), 1);
+    assert_eq!(rewards.last_value(&contract_id), 5_0000000);
+    assert!(client.get_user(&player).unwrap().voucher_issued);
+}
+
+#[test]
+#[should_panic(expected = "Voucher already issued")]
+fn test_auto_minted_voucher_cannot_be_minted_again() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, _) = setup_with_reward_system(&env);
+    client.set_registration_voucher(&2_0000000, &true);
+
+    let player = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "player1"), &player);
+    client.mint_registration_voucher(&player);
+}
+
+#[test]
+fn test_register_player_without_auto_mint_issues_no_voucher() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, rewards) = setup_with_reward_system(&env);
+
+    let player = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "player1"), &player);
+
+    assert_eq!(rewards.minted_count(&player), 0);
+    assert!(!client.get_user(&player).unwrap().voucher_issued);
+}
+
+#[test]
+fn test_get_user_reads_legacy_record() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, _) = setup_with_reward_system(&env);
+
+    let player = Address::generate(&env);
+    store_legacy_user(&env, &contract_id, &player, "OldTimer");
+
+    let user = client.get_user(&player).unwrap();
+    assert_eq!(user.id, 7);
+    assert_eq!(user.username, String::from_str(&env, "OldTimer"));
+    assert_eq!(user.registered_at, 100);
+    assert_eq!(user.games_played, 3);
+    assert_eq!(user.games_won, 1);
+    assert!(!user.voucher_issued);
+    assert_eq!(user.username_changed_at, 0);
+}
+
+#[test]
+fn test_mint_registration_voucher_upgrades_legacy_record() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, rewards) = setup_with_reward_system(&env);
+
+    let player = Address::generate(&env);
+    store_legacy_user(&env, &contract_id, &player, "OldTimer");
+    client.mint_registration_voucher(&player);
+
+    assert_eq!(rewards.minted_count(&player), 1);
+    let user = client.get_user(&player).unwrap();
+    assert!(user.voucher_issued);
+    assert_eq!(user.games_played, 3);
+
+    let legacy_left = env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .has(&storage::DataKey::User(player.clone()))
+    });
+    assert!(!legacy_left);
+}
+
+// ===== USERNAME REGISTRY TESTS =====
+
+#[test]
+fn test_get_address_by_username_case_insensitive() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    let reward_system = Address::generate(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+
+    let player = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "PlayerOne"), &player);
+
+    assert_eq!(
+        client.get_address_by_username(&String::from_str(&env, "playerone")),
+        Some(player.clone())
+    );
+    assert_eq!(
+        client.get_address_by_username(&String::from_str(&env, "PLAYERONE")),
+        Some(player)
+    );
+    assert_eq!(
+        client.get_address_by_username(&String::from_str(&env, "nobody")),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "Username already taken")]
+fn test_register_player_duplicate_username_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    let reward_system = Address::generate(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+
+    client.register_player(&String::from_str(&env, "player1"), &Address::generate(&env));
+    client.register_player(&String::from_str(&env, "player1"), &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Username already taken")]
+fn test_register_player_duplicate_username_different_case_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    let reward_system = Address::generate(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+
+    client.register_player(&String::from_str(&env, "Player1"), &Address::generate(&env));
+    client.register_player(&String::from_str(&env, "pLAYER1"), &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Username is reserved")]
+fn test_register_player_reserved_username_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    let reward_system = Address::generate(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+
+    client.set_username_reserved(&String::from_str(&env, "Admin"), &true);
+    assert!(client.is_username_reserved(&String::from_str(&env, "ADMIN")));
+
+    client.register_player(&String::from_str(&env, "admin"), &Address::generate(&env));
+}
+
+#[test]
+fn test_released_username_can_be_registered() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    let reward_system = Address::generate(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+
+    let name = String::from_str(&env, "support");
+    client.set_username_reserved(&name, &true);
+    client.set_username_reserved(&name, &false);
+    assert!(!client.is_username_reserved(&name));
+
+    let player = Address::generate(&env);
+    client.register_player(&name, &player);
+    assert_eq!(client.get_address_by_username(&name), Some(player));
+}
+
+// ===== USERNAME CHANGE TESTS =====
+
+#[test]
+fn test_change_username_updates_index_and_keeps_stats() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    let reward_system = Address::generate(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+
+    let player = Address::generate(&env);
+    let old_name = String::from_str(&env, "oldname");
+    let new_name = String::from_str(&env, "NewName");
+    client.register_player(&old_name, &player);
+
+    client.change_username(&player, &new_name);
+    assert!(!env.events().all().is_empty());
+
+    let user = client.get_user(&player).unwrap();
+    assert_eq!(user.username, new_name);
+    assert_eq!(user.username_changed_at, 1_000);
+    assert_eq!(user.games_played, 0);
+    assert_eq!(client.get_address_by_username(&new_name), Some(player));
+    assert_eq!(client.get_address_by_username(&old_name), None);
+
+    // The old name is free for someone else
+    client.register_player(&old_name, &Address::generate(&env));
+}
+
+#[test]
+fn test_change_username_charges_tyc_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    let reward_system = Address::generate(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+    client.set_username_change_config(&50, &0);
+    assert_eq!(client.get_username_change_config(), (50, 0));
+
+    let player = Address::generate(&env);
+    StellarAssetClient::new(&env, &tyc_token).mint(&player, &80);
+    client.register_player(&String::from_str(&env, "oldname"), &player);
+
+    client.change_username(&player, &String::from_str(&env, "newname"));
+
+    let tyc_client = TokenClient::new(&env, &tyc_token);
+    assert_eq!(tyc_client.balance(&player), 30);
+    assert_eq!(tyc_client.balance(&contract_id), 50);
+}
+
+#[test]
+fn test_change_username_case_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    let reward_system = Address::generate(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+
+    let player = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "player1"), &player);
+    client.change_username(&player, &String::from_str(&env, "Player1"));
+
+    assert_eq!(
+        client.get_address_by_username(&String::from_str(&env, "PLAYER1")),
+        Some(player)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Username change on cooldown")]
+fn test_change_username_within_cooldown_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    let reward_system = Address::generate(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+    client.set_username_change_config(&0, &3_600);
+
+    let player = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "name1"), &player);
+    client.change_username(&player, &String::from_str(&env, "name2"));
+
+    env.ledger().set_timestamp(4_599);
+    client.change_username(&player, &String::from_str(&env, "name3"));
+}
+
+#[test]
+fn test_change_username_after_cooldown_succeeds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    let reward_system = Address::generate(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+    client.set_username_change_config(&0, &3_600);
+
+    let player = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "name1"), &player);
+    client.change_username(&player, &String::from_str(&env, "name2"));
+
+    env.ledger().set_timestamp(4_600);
+    client.change_username(&player, &String::from_str(&env, "name3"));
+
+    assert_eq!(
+        client.get_user(&player).unwrap().username,
+        String::from_str(&env, "name3")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Username already taken")]
+fn test_change_username_to_taken_name_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    let reward_system = Address::generate(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+
+    let player = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "name1"), &player);
+    client.register_player(&String::from_str(&env, "name2"), &Address::generate(&env));
+
+    client.change_username(&player, &String::from_str(&env, "NAME2"));
+}
+
+#[test]
+#[should_panic(expected = "Player not registered")]
+fn test_change_username_unregistered_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    let reward_system = Address::generate(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+
+    client.change_username(&Address::generate(&env), &String::from_str(&env, "name1"));
+}
+
+// ===== PLAYER ID TESTS =====
+
+#[test]
+fn test_backfill_username_index_claims_legacy_names() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, _) = setup_with_reward_system(&env);
+
+    let veteran = Address::generate(&env);
+    store_legacy_user(&env, &contract_id, &veteran, "OldTimer");
+    assert_eq!(
+        client.get_address_by_username(&String::from_str(&env, "oldtimer")),
+        None
+    );
+
+    client.backfill_username_index(&vec![&env, veteran.clone()]);
+
+    assert_eq!(
+        client.get_address_by_username(&String::from_str(&env, "oldtimer")),
+        Some(veteran)
+    );
+    let result = client.try_register_player(
+        &String::from_str(&env, "OLDTIMER"),
+        &Address::generate(&env),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_backfill_username_index_keeps_existing_holder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, _) = setup_with_reward_system(&env);
+
+    let newcomer = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "oldtimer"), &newcomer);
+    let veteran = Address::generate(&env);
+    store_legacy_user(&env, &contract_id, &veteran, "OldTimer");
+
+    client.backfill_username_index(&vec![&env, veteran.clone()]);
+    assert_eq!(
+        client.get_address_by_username(&String::from_str(&env, "OLDTIMER")),
+        Some(newcomer.clone())
+    );
+
+    // Renaming the unindexed veteran must not free the newcomer's entry
+    client.change_username(&veteran, &String::from_str(&env, "veteran"));
+    assert_eq!(
+        client.get_address_by_username(&String::from_str(&env, "oldtimer")),
+        Some(newcomer)
+    );
+    assert_eq!(
+        client.get_address_by_username(&String::from_str(&env, "veteran")),
+        Some(veteran)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Player not registered")]
+fn test_backfill_username_index_unregistered_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, _) = setup_with_reward_system(&env);
+
+    client.backfill_username_index(&vec![&env, Address::generate(&env)]);
+}
+
+#[test]
+fn test_register_player_assigns_sequential_ids_in_same_ledger() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    let reward_system = Address::generate(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+
+    assert_eq!(client.get_total_players(), 0);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "player1"), &player1);
+    client.register_player(&String::from_str(&env, "player2"), &player2);
+
+    assert_eq!(client.get_user(&player1).unwrap().id, 1);
+    assert_eq!(client.get_user(&player2).unwrap().id, 2);
+    assert_eq!(client.get_total_players(), 2);
+}
+
+#[test]
+fn test_get_user_by_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    let reward_system = Address::generate(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+
+    let player = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "player1"), &player);
+
+    let user = client.get_user_by_id(&1).unwrap();
+    assert_eq!(user.address, player);
+    assert!(client.get_user_by_id(&2).is_none());
+    assert!(client.get_user_by_id(&0).is_none());
+}
+
+#[test]
+fn test_backfill_player_ids_assigns_counter_ids_to_legacy_users() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, _) = setup_with_reward_system(&env);
+
+    let veteran = Address::generate(&env);
+    store_legacy_user(&env, &contract_id, &veteran, "OldTimer");
+    assert_eq!(client.get_user_by_id(&7), None);
+    assert_eq!(client.get_total_players(), 0);
+
+    client.backfill_player_ids(&vec![&env, veteran.clone()]);
+    // Running it again leaves the assigned id alone
+    client.backfill_player_ids(&vec![&env, veteran.clone()]);
+
+    let user = client.get_user_by_id(&1).unwrap();
+    assert_eq!(user.address, veteran);
+    assert_eq!(user.id, 1);
+    assert_eq!(user.games_played, 3);
+    assert_eq!(client.get_total_players(), 1);
+
+    let newcomer = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "newcomer"), &newcomer);
+    assert_eq!(client.get_user(&newcomer).unwrap().id, 2);
+}
+
+#[test]
+#[should_panic(expected = "Player not registered")]
+fn test_backfill_player_ids_unregistered_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, _) = setup_with_reward_system(&env);
+
+    client.backfill_player_ids(&vec![&env, Address::generate(&env)]);
+}
+
+#[test]
+fn test_failed_registration_does_not_consume_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    let reward_system = Address::generate(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+
+    let player1 = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "player1"), &player1);
+    let result =
+        client.try_register_player(&String::from_str(&env, "player1"), &Address::generate(&env));
+    assert!(result.is_err());
+
+    let player2 = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "player2"), &player2);
+
+    assert_eq!(client.get_user(&player2).unwrap().id, 2);
+    assert_eq!(client.get_total_players(), 2);
+}
+
+// ===== GAME RESULT STATS TESTS =====
+
+fn setup_stats_game(env: &Env) -> (TycoonContractClient<'_>, Address, Address, Address, Address) {
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(env);
+    let reward_system = Address::generate(env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+
+    let controller = Address::generate(env);
+    client.set_backend_game_controller(&controller);
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    let player3 = Address::generate(env);
+    client.register_player(&String::from_str(env, "player1"), &player1);
+    client.register_player(&String::from_str(env, "player2"), &player2);
+    client.register_player(&String::from_str(env, "player3"), &player3);
+
+    (client, controller, player1, player2, player3)
+}
+
+#[test]
+fn test_record_game_result_updates_stats() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, controller, player1, player2, player3) = setup_stats_game(&env);
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+
+    assert!(client.record_game_result(&controller, &1, &players, &player2));
+
+    let user1 = client.get_user(&player1).unwrap();
+    let user2 = client.get_user(&player2).unwrap();
+    let user3 = client.get_user(&player3).unwrap();
+    assert_eq!((user1.games_played, user1.games_won), (1, 0));
+    assert_eq!((user2.games_played, user2.games_won), (1, 1));
+    assert_eq!((user3.games_played, user3.games_won), (1, 0));
+    assert!(client.is_game_result_recorded(&1));
+}
+
+#[test]
+fn test_record_game_result_emits_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, controller, player1, player2, _) = setup_stats_game(&env);
+    let players = vec![&env, player1.clone(), player2.clone()];
+
+    client.record_game_result(&controller, &7, &players, &player1);
+
+    assert!(!env.events().all().is_empty());
+}
+
+#[test]
+fn test_record_game_result_is_idempotent() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, controller, player1, player2, _) = setup_stats_game(&env);
+    let players = vec![&env, player1.clone(), player2.clone()];
+
+    assert!(client.record_game_result(&controller, &1, &players, &player1));
+    assert!(!client.record_game_result(&controller, &1, &players, &player1));
+
+    let user1 = client.get_user(&player1).unwrap();
+    let user2 = client.get_user(&player2).unwrap();
+    assert_eq!((user1.games_played, user1.games_won), (1, 1));
+    assert_eq!((user2.games_played, user2.games_won), (1, 0));
+
+    // A different game still counts
+    assert!(client.record_game_result(&controller, &2, &players, &player2));
+    let user2 = client.get_user(&player2).unwrap();
+    assert_eq!((user2.games_played, user2.games_won), (2, 1));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller must be backend game controller")]
+fn test_record_game_result_rejects_non_controller() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _, player1, player2, _) = setup_stats_game(&env);
+    let players = vec![&env, player1.clone(), player2];
+
+    client.record_game_result(&player1, &1, &players, &player1);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller must be backend game controller")]
+fn test_record_game_result_without_controller_set() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &Address::generate(&env));
+    let player = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "player1"), &player);
+
+    client.record_game_result(&owner, &1, &vec![&env, player.clone()], &player);
+}
+
+#[test]
+#[should_panic(expected = "Winner is not a game participant")]
+fn test_record_game_result_winner_not_participant() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, controller, player1, player2, player3) = setup_stats_game(&env);
+    let players = vec![&env, player1, player2];
+
+    client.record_game_result(&controller, &1, &players, &player3);
+}
+
+#[test]
+#[should_panic(expected = "Duplicate player")]
+fn test_record_game_result_duplicate_player() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, controller, player1, player2, _) = setup_stats_game(&env);
+    let players = vec![&env, player1.clone(), player2, player1.clone()];
+
+    client.record_game_result(&controller, &1, &players, &player1);
+}
+
+#[test]
+#[should_panic(expected = "Player not registered")]
+fn test_record_game_result_unregistered_player() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, controller, player1, _, _) = setup_stats_game(&env);
+    let players = vec![&env, player1.clone(), Address::generate(&env)];
+
+    client.record_game_result(&controller, &1, &players, &player1);
+}
+
+// ===== GAME REMOVAL PAYOUT TESTS =====
+
+fn setup_refund_game(
+    env: &Env,
+) -> (
+    Address,
+    TycoonContractClient<'_>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let (contract_id, client, owner, tyc_token, usdc_token) = setup_contract(env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    let players = vec![env, player1.clone(), player2.clone()];
+    register_staked_game(env, &client, &owner, &usdc_token, 1, &players, 1000);
+
+    // Full refund up to turn 5, half up to turn 20, nothing after
+    let schedule = vec![
+        env,
+        RefundTier {
+            max_turn: 5,
+            refund_bps: 10_000,
+        },
+        RefundTier {
+            max_turn: 20,
+            refund_bps: 5_000,
+        },
+    ];
+    client.set_refund_schedule(&schedule);
+
+    (contract_id, client, owner, usdc_token, player1, player2)
+}
+
+#[test]
+fn test_register_game_escrows_stakes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, _, usdc_token, player1, player2) = setup_refund_game(&env);
+    let usdc = TokenClient::new(&env, &usdc_token);
+
+    assert_eq!(usdc.balance(&contract_id), 2000);
+    assert_eq!(usdc.balance(&player1), 0);
+
+    let game = client.get_game(&1).unwrap();
+    assert_eq!(game.players, vec![&env, player1, player2]);
+    assert!(game.removed.is_empty());
+    assert_eq!(game.stake_per_player, 1000);
+}
+
+#[test]
+#[should_panic(expected = "Game already exists")]
+fn test_register_game_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, _, player1, _) = setup_refund_game(&env);
+    client.register_game(&owner, &1, &vec![&env, player1], &0);
+}
+
+#[test]
+#[should_panic(expected = "Duplicate player")]
+fn test_register_game_duplicate_player() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, _, player1, _) = setup_refund_game(&env);
+    client.register_game(&owner, &2, &vec![&env, player1.clone(), player1], &0);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller must be owner or backend game controller")]
+fn test_register_game_unauthorized() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, _, _, player1, _) = setup_refund_game(&env);
+    client.register_game(&player1, &2, &vec![&env, player1.clone()], &0);
+}
+
+#[test]
+fn test_remove_player_full_refund_early() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, owner, usdc_token, player1, player2) = setup_refund_game(&env);
+    let usdc = TokenClient::new(&env, &usdc_token);
+
+    client.remove_player_from_game(&owner, &1, &player1, &5);
+
+    assert_eq!(usdc.balance(&player1), 1000);
+    assert_eq!(usdc.balance(&contract_id), 1000);
+
+    let game = client.get_game(&1).unwrap();
+    assert_eq!(game.players, vec![&env, player2]);
+    assert_eq!(game.removed, vec![&env, player1]);
+}
+
+#[test]
+fn test_remove_player_partial_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, owner, usdc_token, player1, _) = setup_refund_game(&env);
+    let usdc = TokenClient::new(&env, &usdc_token);
+
+    client.remove_player_from_game(&owner, &1, &player1, &6);
+
+    assert_eq!(usdc.balance(&player1), 500);
+    assert_eq!(usdc.balance(&contract_id), 1500);
+}
+
+#[test]
+fn test_remove_player_forfeits_after_last_tier() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, owner, usdc_token, player1, _) = setup_refund_game(&env);
+    let usdc = TokenClient::new(&env, &usdc_token);
+
+    client.remove_player_from_game(&owner, &1, &player1, &21);
+
+    assert_eq!(usdc.balance(&player1), 0);
+    assert_eq!(usdc.balance(&contract_id), 2000);
+    assert!(!client.get_game(&1).unwrap().players.contains(&player1));
+}
+
+#[test]
+fn test_remove_player_default_schedule_forfeits() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &Address::generate(&env));
+    assert!(client.get_refund_schedule().is_empty());
+
+    let player = Address::generate(&env);
+    let players = vec![&env, player.clone()];
+    register_staked_game(&env, &client, &owner, &usdc_token, 1, &players, 1000);
+
+    client.remove_player_from_game(&owner, &1, &player, &0);
+
+    assert_eq!(TokenClient::new(&env, &usdc_token).balance(&player), 0);
+}
+
+#[test]
+#[should_panic(expected = "Player already removed")]
+fn test_remove_player_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, _, player1, _) = setup_refund_game(&env);
+
+    client.remove_player_from_game(&owner, &1, &player1, &1);
+    client.remove_player_from_game(&owner, &1, &player1, &1);
+}
+
+#[test]
+#[should_panic(expected = "Player not in game")]
+fn test_remove_player_not_in_game() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, _, _, _) = setup_refund_game(&env);
+    client.remove_player_from_game(&owner, &1, &Address::generate(&env), &1);
+}
+
+#[test]
+#[should_panic(expected = "Game not found")]
+fn test_remove_player_game_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, _, player1, _) = setup_refund_game(&env);
+    client.remove_player_from_game(&owner, &99, &player1, &1);
+}
+
+#[test]
+#[should_panic(expected = "Refund exceeds 10000 bps")]
+fn test_set_refund_schedule_bps_too_high() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, _, _, _, _) = setup_refund_game(&env);
+    let schedule = vec![
+        &env,
+        RefundTier {
+            max_turn: 1,
+            refund_bps: 10_001,
+        },
+    ];
+    client.set_refund_schedule(&schedule);
+}
+
+#[test]
+#[should_panic(expected = "Refund tiers must be in increasing turn order")]
+fn test_set_refund_schedule_unordered() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, _, _, _, _) = setup_refund_game(&env);
+    let schedule = vec![
+        &env,
+        RefundTier {
+            max_turn: 10,
+            refund_bps: 5_000,
+        },
+        RefundTier {
+            max_turn: 10,
+            refund_bps: 1_000,
+        },
+    ];
+    client.set_refund_schedule(&schedule);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient contract balance")]
+fn test_withdraw_funds_excludes_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, _, usdc_token, _, _) = setup_refund_game(&env);
+    client.withdraw_funds(&usdc_token, &Address::generate(&env), &1);
+}
+
+#[test]
+fn test_forfeited_stake_stays_in_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, usdc_token, player1, player2) = setup_refund_game(&env);
+    let usdc = TokenClient::new(&env, &usdc_token);
+
+    // Half refunded, half forfeited to the pot
+    client.remove_player_from_game(&owner, &1, &player1, &6);
+    assert_eq!(client.get_escrowed_usdc(), 1500);
+    assert_eq!(client.get_game(&1).unwrap().escrow, 1500);
+
+    let recipient = Address::generate(&env);
+    let result = client.try_withdraw_funds(&usdc_token, &recipient, &500);
+    assert!(result.is_err());
+
+    client.settle_game(&owner, &1, &player2);
+    assert_eq!(usdc.balance(&player2), 1500);
+}
+
+#[test]
+fn test_removing_last_player_releases_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, usdc_token, player1, player2) = setup_refund_game(&env);
+    let usdc = TokenClient::new(&env, &usdc_token);
+
+    client.remove_player_from_game(&owner, &1, &player1, &21);
+    client.remove_player_from_game(&owner, &1, &player2, &6);
+    assert_eq!(usdc.balance(&player2), 500);
+
+    // Nobody is left to win the forfeited stakes
+    let game = client.get_game(&1).unwrap();
+    assert!(game.settled);
+    assert_eq!(game.escrow, 0);
+    assert_eq!(client.get_escrowed_usdc(), 0);
+
+    let recipient = Address::generate(&env);
+    client.withdraw_funds(&usdc_token, &recipient, &1500);
+    assert_eq!(usdc.balance(&recipient), 1500);
+}
+
+#[test]
+fn test_settle_game_pays_escrow_to_winner() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, owner, usdc_token, player1, player2) = setup_refund_game(&env);
+    let usdc = TokenClient::new(&env, &usdc_token);
+
+    client.remove_player_from_game(&owner, &1, &player1, &21);
+    client.settle_game(&owner, &1, &player2);
+
+    assert_eq!(usdc.balance(&player2), 2000);
+    assert_eq!(usdc.balance(&contract_id), 0);
+    assert_eq!(client.get_escrowed_usdc(), 0);
+
+    let game = client.get_game(&1).unwrap();
+    assert!(game.settled);
+    assert_eq!(game.escrow, 0);
+}
+
+#[test]
+#[should_panic(expected = "Game already settled")]
+fn test_settle_game_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, _, player1, _) = setup_refund_game(&env);
+    client.settle_game(&owner, &1, &player1);
+    client.settle_game(&owner, &1, &player1);
+}
+
+#[test]
+#[should_panic(expected = "Game already settled")]
+fn test_remove_player_after_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, _, player1, player2) = setup_refund_game(&env);
+    client.settle_game(&owner, &1, &player1);
+    client.remove_player_from_game(&owner, &1, &player2, &1);
+}
+
+#[test]
+#[should_panic(expected = "Winner is not in game")]
+fn test_settle_game_with_removed_winner() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, _, player1, _) = setup_refund_game(&env);
+    client.remove_player_from_game(&owner, &1, &player1, &1);
+    client.settle_game(&owner, &1, &player1);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller must be owner or backend game controller")]
+fn test_settle_game_unauthorized() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, _, _, player1, _) = setup_refund_game(&env);
+    client.settle_game(&player1, &1, &player1);
+}
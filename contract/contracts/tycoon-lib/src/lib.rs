@@ -88,7 +88,6 @@ pub enum PlayerSymbol {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::Env;
 
     #[test]
     fn test_game_status_variants_compile_and_are_distinct() {
@@ -0,0 +1,39 @@
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+/// Game result signed off-chain by the registered result signer.
+///
+/// The signed message is the XDR encoding of this struct. `contract` binds
+/// the signature to one deployment so it cannot be replayed elsewhere.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameResultPayload {
+    /// Address of the tycoon-main-game contract the result is meant for.
+    pub contract: Address,
+    /// Game being settled.
+    pub game_id: u64,
+    /// Ranked finishing order, 1st place first.
+    pub placements: Vec<Address>,
+    /// Hash of the final off-chain game state.
+    pub state_hash: BytesN<32>,
+    /// Per-game nonce; must exceed the last nonce accepted for the game.
+    pub nonce: u64,
+}
+
+/// Returns the message the result signer signs for `payload`.
+pub fn message(env: &Env, payload: &GameResultPayload) -> Bytes {
+    payload.clone().to_xdr(env)
+}
+
+/// Verifies `signature` over `payload` against `public_key`.
+///
+/// # Panics
+/// Traps if the signature is invalid.
+pub fn verify(
+    env: &Env,
+    public_key: &BytesN<32>,
+    payload: &GameResultPayload,
+    signature: &BytesN<64>,
+) {
+    env.crypto()
+        .ed25519_verify(public_key, &message(env, payload), signature);
+}
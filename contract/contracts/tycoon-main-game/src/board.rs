@@ -0,0 +1,183 @@
+/// Number of squares on the board.
+pub const BOARD_SIZE: u32 = 40;
+
+/// In-game cash collected for passing Go.
+pub const GO_SALARY: u128 = 200;
+
+/// Square players are sent to by the Go To Jail square.
+pub const JAIL_SQUARE: u32 = 10;
+/// In-game cash paid to the bank to leave jail.
+pub const JAIL_BAIL: u128 = 50;
+/// Turns a jailed player may try to roll doubles before bail is forced.
+pub const MAX_JAIL_ATTEMPTS: u32 = 3;
+
+/// Color group shared by the four railroads.
+pub const RAILROAD_GROUP: u32 = 8;
+/// Color group shared by the two utilities.
+pub const UTILITY_GROUP: u32 = 9;
+
+/// Development level of a property with a hotel (four houses come first).
+pub const HOTEL_LEVEL: u32 = 5;
+
+/// Rent multiplier over base rent for each development level: none, 1–4
+/// houses, hotel.
+const RENT_MULTIPLIERS: [u128; HOTEL_LEVEL as usize + 1] = [1, 5, 15, 45, 80, 125];
+
+/// What happens when a player lands on a square.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SquareKind {
+    Go,
+    Property,
+    Railroad,
+    Utility,
+    /// Pays `Square.price` to the bank.
+    Tax,
+    /// Card squares; cards are drawn off-chain and have no on-chain effect.
+    Chance,
+    CommunityChest,
+    Jail,
+    FreeParking,
+    GoToJail,
+}
+
+/// Static definition of one board square.
+///
+/// For ownable squares `price` is the list price and `rent` the base rent;
+/// for `Tax` squares `price` is the tax due. `group` is the color set
+/// (0–7), `RAILROAD_GROUP` or `UTILITY_GROUP`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Square {
+    pub kind: SquareKind,
+    pub price: u128,
+    pub rent: u128,
+    pub group: u32,
+    pub house_cost: u128,
+}
+
+impl Square {
+    /// Whether the square can be bought and owned.
+    pub fn is_ownable(&self) -> bool {
+        matches!(
+            self.kind,
+            SquareKind::Property | SquareKind::Railroad | SquareKind::Utility
+        )
+    }
+}
+
+const fn special(kind: SquareKind) -> Square {
+    Square {
+        kind,
+        price: 0,
+        rent: 0,
+        group: 0,
+        house_cost: 0,
+    }
+}
+
+const fn tax(amount: u128) -> Square {
+    Square {
+        kind: SquareKind::Tax,
+        price: amount,
+        rent: 0,
+        group: 0,
+        house_cost: 0,
+    }
+}
+
+const fn property(price: u128, rent: u128, group: u32, house_cost: u128) -> Square {
+    Square {
+        kind: SquareKind::Property,
+        price,
+        rent,
+        group,
+        house_cost,
+    }
+}
+
+const fn railroad() -> Square {
+    Square {
+        kind: SquareKind::Railroad,
+        price: 200,
+        rent: 25,
+        group: RAILROAD_GROUP,
+        house_cost: 0,
+    }
+}
+
+const fn utility() -> Square {
+    Square {
+        kind: SquareKind::Utility,
+        price: 150,
+        rent: 0,
+        group: UTILITY_GROUP,
+        house_cost: 0,
+    }
+}
+
+/// The classic 40-square board, starting at Go.
+const BOARD: [Square; BOARD_SIZE as usize] = [
+    special(SquareKind::Go),
+    property(60, 2, 0, 50),
+    special(SquareKind::CommunityChest),
+    property(60, 4, 0, 50),
+    tax(200),
+    railroad(),
+    property(100, 6, 1, 50),
+    special(SquareKind::Chance),
+    property(100, 6, 1, 50),
+    property(120, 8, 1, 50),
+    special(SquareKind::Jail),
+    property(140, 10, 2, 100),
+    utility(),
+    property(140, 10, 2, 100),
+    property(160, 12, 2, 100),
+    railroad(),
+    property(180, 14, 3, 100),
+    special(SquareKind::CommunityChest),
+    property(180, 14, 3, 100),
+    property(200, 16, 3, 100),
+    special(SquareKind::FreeParking),
+    property(220, 18, 4, 150),
+    special(SquareKind::Chance),
+    property(220, 18, 4, 150),
+    property(240, 20, 4, 150),
+    railroad(),
+    property(260, 22, 5, 150),
+    property(260, 22, 5, 150),
+    utility(),
+    property(280, 24, 5, 150),
+    special(SquareKind::GoToJail),
+    property(300, 26, 6, 200),
+    property(300, 26, 6, 200),
+    special(SquareKind::CommunityChest),
+    property(320, 28, 6, 200),
+    railroad(),
+    special(SquareKind::Chance),
+    property(350, 35, 7, 200),
+    tax(100),
+    property(400, 50, 7, 200),
+];
+
+/// Returns the definition of square `index`.
+///
+/// # Panics
+/// - `"Invalid square"` — `index` is off the board.
+pub fn square(index: u32) -> Square {
+    if index >= BOARD_SIZE {
+        panic!("Invalid square");
+    }
+    BOARD[index as usize]
+}
+
+/// Returns the indices of every square in color `group`.
+pub fn group_squares(group: u32) -> impl Iterator<Item = u32> {
+    (0..BOARD_SIZE).filter(move |&i| {
+        let sq = BOARD[i as usize];
+        sq.is_ownable() && sq.group == group
+    })
+}
+
+/// Rent for a property with `level` houses (`HOTEL_LEVEL` for a hotel).
+pub fn developed_rent(base_rent: u128, level: u32) -> u128 {
+    base_rent * RENT_MULTIPLIERS[level.min(HOTEL_LEVEL) as usize]
+}
@@ -0,0 +1,45 @@
+use soroban_sdk::{Bytes, BytesN, Env};
+
+/// Faces on each die.
+const DIE_FACES: u64 = 6;
+
+/// Returns the commitment for a dice seed: `sha256(seed)`.
+pub fn commitment(env: &Env, seed: &BytesN<32>) -> BytesN<32> {
+    env.crypto().sha256(&Bytes::from(seed)).into()
+}
+
+/// Derives two die faces (1–6) from the revealed player and backend seeds.
+///
+/// The roll is `sha256(player_seed || backend_seed)`; each die takes eight
+/// bytes of the digest modulo 6, so anyone holding both seeds can recompute
+/// it.
+pub fn derive_roll(env: &Env, player_seed: &BytesN<32>, backend_seed: &BytesN<32>) -> (u32, u32) {
+    let mut preimage = Bytes::from(player_seed);
+    preimage.append(&Bytes::from(backend_seed));
+    faces(env.crypto().sha256(&preimage).to_array())
+}
+
+/// Rolls two dice from the ledger PRNG, used when the backend did not
+/// reveal, so nobody could have known the outcome.
+pub fn fallback_roll(env: &Env) -> (u32, u32) {
+    let first: u64 = env.prng().gen_range(1..=DIE_FACES);
+    let second: u64 = env.prng().gen_range(1..=DIE_FACES);
+    (first as u32, second as u32)
+}
+
+/// Splits a digest into two die faces, eight bytes each.
+fn faces(digest: [u8; 32]) -> (u32, u32) {
+    let mut first = [0u8; 8];
+    let mut second = [0u8; 8];
+    first.copy_from_slice(&digest[0..8]);
+    second.copy_from_slice(&digest[8..16]);
+
+    (
+        face(u64::from_be_bytes(first)),
+        face(u64::from_be_bytes(second)),
+    )
+}
+
+fn face(value: u64) -> u32 {
+    (value % DIE_FACES + 1) as u32
+}
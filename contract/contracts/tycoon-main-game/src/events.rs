@@ -1,4 +1,59 @@
-use soroban_sdk::{contracttype, Address, Env, Symbol};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Symbol, Vec};
+
+use crate::storage::{GameMode, JailRelease};
+
+/// Data payload for PlayerRegistered event. `voucher_id` is `None` when
+/// the registration voucher is disabled.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PlayerRegisteredData {
+    pub player: Address,
+    pub username: String,
+    pub voucher_id: Option<u128>,
+    pub voucher_value: u128,
+}
+
+/// Emits PlayerRegistered when a new player registers.
+pub fn emit_player_registered(env: &Env, data: &PlayerRegisteredData) {
+    let topics = (Symbol::new(env, "PlayerRegistered"), data.player.clone());
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for GameCreated event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct GameCreatedData {
+    pub game_id: u64,
+    pub creator: Address,
+    pub mode: GameMode,
+    pub max_players: u32,
+    pub stake_per_player: u128,
+}
+
+/// Emits GameCreated when a new lobby is opened.
+pub fn emit_game_created(env: &Env, data: &GameCreatedData) {
+    let topics = (Symbol::new(env, "GameCreated"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for PlayerJoined event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PlayerJoinedData {
+    pub game_id: u64,
+    pub player: Address,
+    pub stake_paid: u128,
+    pub joined_players: u32,
+}
+
+/// Emits PlayerJoined when a player successfully joins a pending game.
+pub fn emit_player_joined(env: &Env, data: &PlayerJoinedData) {
+    let topics = (Symbol::new(env, "PlayerJoined"), data.player.clone());
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
 
 /// Data payload for PlayerLeftPending event.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -31,3 +86,566 @@ pub fn emit_pending_game_ended(env: &Env, data: &PendingGameEndedData) {
     #[allow(deprecated)]
     env.events().publish(topics, data);
 }
+
+/// Data payload for GameCancelled and GameExpired events — emitted when a
+/// pending lobby is closed and every joined player refunded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PendingGameClosedData {
+    pub game_id: u64,
+    pub players_refunded: u32,
+    pub total_refunded: u128,
+}
+
+/// Emits GameCancelled when the creator cancels a pending game.
+pub fn emit_game_cancelled(env: &Env, data: &PendingGameClosedData) {
+    let topics = (Symbol::new(env, "GameCancelled"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Emits GameExpired when a pending game's lobby timeout elapses.
+pub fn emit_game_expired(env: &Env, data: &PendingGameClosedData) {
+    let topics = (Symbol::new(env, "GameExpired"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for GameAbandoned event, emitted when players refund an
+/// ongoing game that was not settled before its settlement deadline.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct GameAbandonedData {
+    pub game_id: u64,
+    pub triggered_by: Address,
+    pub players_refunded: u32,
+    pub total_refunded: u128,
+}
+
+/// Emits GameAbandoned when an unsettled game is refunded by its players.
+pub fn emit_game_abandoned(env: &Env, data: &GameAbandonedData) {
+    let topics = (Symbol::new(env, "GameAbandoned"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for GameStarted event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct GameStartedData {
+    pub game_id: u64,
+    pub turn_order: Vec<Address>,
+    pub started_at: u64,
+}
+
+/// Emits GameStarted when a game moves from `Pending` to `Ongoing`.
+pub fn emit_game_started(env: &Env, data: &GameStartedData) {
+    let topics = (Symbol::new(env, "GameStarted"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for GameSettled event — `fee` is the protocol rake taken
+/// from `pot`, and `payouts[i]` is the amount paid to `placements[i]`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct GameSettledData {
+    pub game_id: u64,
+    pub winner: Address,
+    pub pot: u128,
+    pub fee: u128,
+    pub placements: Vec<Address>,
+    pub payouts: Vec<u128>,
+}
+
+/// Emits GameSettled when an ongoing game ends and its pot is paid out.
+pub fn emit_game_settled(env: &Env, data: &GameSettledData) {
+    let topics = (Symbol::new(env, "GameSettled"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for ProtocolFeeUpdated event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ProtocolFeeUpdatedData {
+    pub old_fee_bps: u32,
+    pub new_fee_bps: u32,
+}
+
+/// Emits ProtocolFeeUpdated when the owner changes the protocol fee.
+pub fn emit_protocol_fee_updated(env: &Env, data: &ProtocolFeeUpdatedData) {
+    let topics = (Symbol::new(env, "ProtocolFeeUpdated"),);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for TreasuryWithdrawn event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TreasuryWithdrawnData {
+    pub token: Address,
+    pub to: Address,
+    pub amount: u128,
+}
+
+/// Emits TreasuryWithdrawn when the owner withdraws accrued protocol fees.
+pub fn emit_treasury_withdrawn(env: &Env, data: &TreasuryWithdrawnData) {
+    let topics = (Symbol::new(env, "TreasuryWithdrawn"), data.token.clone());
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for TurnStarted event — `deadline` is the timestamp after
+/// which `claim_timeout` can eliminate `player`, or zero if turn timeouts are
+/// disabled.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TurnStartedData {
+    pub game_id: u64,
+    pub player: Address,
+    pub turn_number: u32,
+    pub deadline: u64,
+}
+
+/// Emits TurnStarted whenever a new turn begins in an ongoing game.
+pub fn emit_turn_started(env: &Env, data: &TurnStartedData) {
+    let topics = (Symbol::new(env, "TurnStarted"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for PlayerTimedOut event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PlayerTimedOutData {
+    pub game_id: u64,
+    pub player: Address,
+    pub claimed_by: Address,
+    pub turn_number: u32,
+}
+
+/// Emits PlayerTimedOut when an idle player is eliminated via `claim_timeout`.
+pub fn emit_player_timed_out(env: &Env, data: &PlayerTimedOutData) {
+    let topics = (Symbol::new(env, "PlayerTimedOut"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for SignedResultAccepted event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SignedResultAcceptedData {
+    pub game_id: u64,
+    pub relayer: Address,
+    pub state_hash: BytesN<32>,
+    pub nonce: u64,
+}
+
+/// Emits SignedResultAccepted when a signed game result is verified.
+pub fn emit_signed_result_accepted(env: &Env, data: &SignedResultAcceptedData) {
+    let topics = (Symbol::new(env, "SignedResultAccepted"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for ResultSignerUpdated event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ResultSignerUpdatedData {
+    pub old_signer: Option<BytesN<32>>,
+    pub new_signer: BytesN<32>,
+}
+
+/// Emits ResultSignerUpdated when the owner registers a result signing key.
+pub fn emit_result_signer_updated(env: &Env, data: &ResultSignerUpdatedData) {
+    let topics = (Symbol::new(env, "ResultSignerUpdated"),);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for ResultReported event — the result settles at
+/// `finalizes_at` unless disputed first.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ResultReportedData {
+    pub game_id: u64,
+    pub placements: Vec<Address>,
+    pub finalizes_at: u64,
+}
+
+/// Emits ResultReported when a result enters its dispute window.
+pub fn emit_result_reported(env: &Env, data: &ResultReportedData) {
+    let topics = (Symbol::new(env, "ResultReported"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for ResultDisputed event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ResultDisputedData {
+    pub game_id: u64,
+    pub disputed_by: Address,
+    pub bond: u128,
+}
+
+/// Emits ResultDisputed when a participant disputes a reported result.
+pub fn emit_result_disputed(env: &Env, data: &ResultDisputedData) {
+    let topics = (Symbol::new(env, "ResultDisputed"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for DisputeResolved event — `upheld` is true when the
+/// reported placements stood and the bond was forfeited.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DisputeResolvedData {
+    pub game_id: u64,
+    pub resolved_by: Address,
+    pub upheld: bool,
+    pub placements: Vec<Address>,
+}
+
+/// Emits DisputeResolved when the owner or arbiter resolves a dispute.
+pub fn emit_dispute_resolved(env: &Env, data: &DisputeResolvedData) {
+    let topics = (Symbol::new(env, "DisputeResolved"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for DiceCommitted event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DiceCommittedData {
+    pub game_id: u64,
+    pub turn_number: u32,
+    pub committer: Address,
+    pub commitment: BytesN<32>,
+    pub reveal_deadline: u64,
+}
+
+/// Emits DiceCommitted when a party commits to a dice seed.
+pub fn emit_dice_committed(env: &Env, data: &DiceCommittedData) {
+    let topics = (Symbol::new(env, "DiceCommitted"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for DiceRevealed event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DiceRevealedData {
+    pub game_id: u64,
+    pub turn_number: u32,
+    pub revealer: Address,
+    pub seed: BytesN<32>,
+}
+
+/// Emits DiceRevealed when a party reveals a seed matching its commitment.
+pub fn emit_dice_revealed(env: &Env, data: &DiceRevealedData) {
+    let topics = (Symbol::new(env, "DiceRevealed"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for DiceRolled event — `fallback` is true when the roll was
+/// settled by `resolve_dice_timeout` because a party failed to reveal.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DiceRolledData {
+    pub game_id: u64,
+    pub turn_number: u32,
+    pub player: Address,
+    pub die1: u32,
+    pub die2: u32,
+    pub fallback: bool,
+}
+
+/// Emits DiceRolled when a turn's dice outcome is fixed.
+pub fn emit_dice_rolled(env: &Env, data: &DiceRolledData) {
+    let topics = (Symbol::new(env, "DiceRolled"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for DiceForfeited event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DiceForfeitedData {
+    pub game_id: u64,
+    pub turn_number: u32,
+    pub player: Address,
+}
+
+/// Emits DiceForfeited when the player loses their move for withholding
+/// their dice reveal.
+pub fn emit_dice_forfeited(env: &Env, data: &DiceForfeitedData) {
+    let topics = (Symbol::new(env, "DiceForfeited"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for PlayerMoved event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PlayerMovedData {
+    pub game_id: u64,
+    pub player: Address,
+    pub from: u32,
+    pub to: u32,
+    pub passed_go: bool,
+}
+
+/// Emits PlayerMoved when a player's token moves by their dice roll.
+pub fn emit_player_moved(env: &Env, data: &PlayerMovedData) {
+    let topics = (Symbol::new(env, "PlayerMoved"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for PropertyPurchased event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PropertyPurchasedData {
+    pub game_id: u64,
+    pub player: Address,
+    pub square: u32,
+    pub price: u128,
+}
+
+/// Emits PropertyPurchased when a player buys a property from the bank.
+pub fn emit_property_purchased(env: &Env, data: &PropertyPurchasedData) {
+    let topics = (Symbol::new(env, "PropertyPurchased"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for RentPaid event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RentPaidData {
+    pub game_id: u64,
+    pub payer: Address,
+    pub owner: Address,
+    pub square: u32,
+    pub amount: u128,
+}
+
+/// Emits RentPaid when a player pays rent to a property owner.
+pub fn emit_rent_paid(env: &Env, data: &RentPaidData) {
+    let topics = (Symbol::new(env, "RentPaid"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for TaxPaid event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TaxPaidData {
+    pub game_id: u64,
+    pub player: Address,
+    pub square: u32,
+    pub amount: u128,
+}
+
+/// Emits TaxPaid when a player lands on a tax square and pays the bank.
+pub fn emit_tax_paid(env: &Env, data: &TaxPaidData) {
+    let topics = (Symbol::new(env, "TaxPaid"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for AuctionOpened event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AuctionOpenedData {
+    pub game_id: u64,
+    pub square: u32,
+    pub ends_at: u64,
+}
+
+/// Emits AuctionOpened when an unowned property goes up for auction.
+pub fn emit_auction_opened(env: &Env, data: &AuctionOpenedData) {
+    let topics = (Symbol::new(env, "AuctionOpened"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for BidPlaced event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BidPlacedData {
+    pub game_id: u64,
+    pub square: u32,
+    pub bidder: Address,
+    pub amount: u128,
+}
+
+/// Emits BidPlaced when a player takes the lead in an auction.
+pub fn emit_bid_placed(env: &Env, data: &BidPlacedData) {
+    let topics = (Symbol::new(env, "BidPlaced"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for AuctionSettled event — `winner` is `None` when nobody
+/// bid and the property stays with the bank.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AuctionSettledData {
+    pub game_id: u64,
+    pub square: u32,
+    pub winner: Option<Address>,
+    pub price: u128,
+}
+
+/// Emits AuctionSettled when an auction closes.
+pub fn emit_auction_settled(env: &Env, data: &AuctionSettledData) {
+    let topics = (Symbol::new(env, "AuctionSettled"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for TradeProposed event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TradeProposedData {
+    pub game_id: u64,
+    pub trade_id: u32,
+    pub proposer: Address,
+    pub counterparty: Address,
+    pub give_squares: Vec<u32>,
+    pub give_cash: u128,
+    pub want_squares: Vec<u32>,
+    pub want_cash: u128,
+}
+
+/// Emits TradeProposed when a player offers a trade.
+pub fn emit_trade_proposed(env: &Env, data: &TradeProposedData) {
+    let topics = (Symbol::new(env, "TradeProposed"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for TradeAccepted event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TradeAcceptedData {
+    pub game_id: u64,
+    pub trade_id: u32,
+}
+
+/// Emits TradeAccepted after a trade has been executed.
+pub fn emit_trade_accepted(env: &Env, data: &TradeAcceptedData) {
+    let topics = (Symbol::new(env, "TradeAccepted"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for TradeRejected event — `counter_id` is set when the
+/// offer was replaced by a counter-offer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TradeRejectedData {
+    pub game_id: u64,
+    pub trade_id: u32,
+    pub rejected_by: Address,
+    pub counter_id: Option<u32>,
+}
+
+/// Emits TradeRejected when an offer is rejected, withdrawn or countered.
+pub fn emit_trade_rejected(env: &Env, data: &TradeRejectedData) {
+    let topics = (Symbol::new(env, "TradeRejected"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for PropertyDeveloped event — `level` is the new number of
+/// houses, or 5 for a hotel.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PropertyDevelopedData {
+    pub game_id: u64,
+    pub owner: Address,
+    pub square: u32,
+    pub level: u32,
+}
+
+/// Emits PropertyDeveloped when a house or hotel is built or sold.
+pub fn emit_property_developed(env: &Env, data: &PropertyDevelopedData) {
+    let topics = (Symbol::new(env, "PropertyDeveloped"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for MortgageChanged event — `amount` is the cash paid out
+/// when mortgaging, or paid in (with interest) when unmortgaging.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MortgageChangedData {
+    pub game_id: u64,
+    pub owner: Address,
+    pub square: u32,
+    pub mortgaged: bool,
+    pub amount: u128,
+}
+
+/// Emits MortgageChanged when a property is mortgaged or unmortgaged.
+pub fn emit_mortgage_changed(env: &Env, data: &MortgageChangedData) {
+    let topics = (Symbol::new(env, "MortgageChanged"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for PlayerBankrupt event — `creditor` is `None` when the
+/// debt was owed to the bank.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PlayerBankruptData {
+    pub game_id: u64,
+    pub player: Address,
+    pub creditor: Option<Address>,
+    pub debt: u128,
+    pub cash: u128,
+    pub properties: Vec<u32>,
+}
+
+/// Emits PlayerBankrupt when a player cannot cover a debt and is eliminated.
+pub fn emit_player_bankrupt(env: &Env, data: &PlayerBankruptData) {
+    let topics = (Symbol::new(env, "PlayerBankrupt"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for PlayerJailed event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PlayerJailedData {
+    pub game_id: u64,
+    pub player: Address,
+}
+
+/// Emits PlayerJailed when a player is sent to jail.
+pub fn emit_player_jailed(env: &Env, data: &PlayerJailedData) {
+    let topics = (Symbol::new(env, "PlayerJailed"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for JailReleased event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct JailReleasedData {
+    pub game_id: u64,
+    pub player: Address,
+    pub release: JailRelease,
+}
+
+/// Emits JailReleased when a player leaves jail.
+pub fn emit_jail_released(env: &Env, data: &JailReleasedData) {
+    let topics = (Symbol::new(env, "JailReleased"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
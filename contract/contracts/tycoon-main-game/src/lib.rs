@@ -1,14 +1,39 @@
 #![no_std]
 
+mod attestation;
+mod board;
+mod dice;
 mod events;
+mod payout;
 #[allow(dead_code)]
 mod storage;
 
 #[cfg(test)]
 mod test;
 
-use soroban_sdk::{contract, contractimpl, token, Address, Env, Vec};
-use storage::{Game, GameSettings, GameStatus};
+use attestation::GameResultPayload;
+use soroban_sdk::{
+    contract, contractimpl, token, vec, Address, BytesN, Env, IntoVal, String, Symbol, Vec,
+};
+use storage::{
+    Auction, DiceRound, Game, GameMode, GameSettings, GameStatus, JailRelease, PendingResult,
+    TradeOffer, TurnState,
+};
+
+/// Smallest lobby size accepted by `create_game`.
+const MIN_PLAYERS: u32 = 2;
+/// Largest lobby size accepted by `create_game`.
+const MAX_PLAYERS: u32 = 8;
+/// Upper bound on the protocol fee, in basis points (10%).
+const MAX_PROTOCOL_FEE_BPS: u32 = 1_000;
+/// Seconds after the first dice commitment before commits and reveals close.
+const DICE_REVEAL_WINDOW: u64 = 300;
+/// Seconds a property auction accepts bids.
+const AUCTION_DURATION: u64 = 300;
+/// Interest charged on top of the mortgage value to lift a mortgage.
+const MORTGAGE_INTEREST_BPS: u128 = 1_000;
+/// Discriminant of tycoon-collectibles `Perk::JailFree`.
+const JAIL_FREE_PERK: u32 = 6;
 
 #[contract]
 pub struct TycoonMainGame;
@@ -35,123 +60,2986 @@ impl TycoonMainGame {
         storage::set_initialized(&env);
     }
 
-    /// Stub: Register a player for the main game.
+    /// Register a player for the main game and mint their welcome voucher.
+    ///
+    /// Validates:
+    /// - `player` is not already registered.
+    /// - `username` is 3–20 characters.
+    ///
+    /// On success:
+    /// - Marks the player registered and stores their username.
+    /// - If the registration voucher value is non-zero (it is zero until the
+    ///   owner opts in), calls the reward system's `mint_voucher` with this
+    ///   contract as the minter. The reward system must list this contract as
+    ///   its backend minter (see `set_registration_voucher_value`); if the
+    ///   mint fails, the whole registration reverts.
+    /// - Emits `PlayerRegistered`.
+    ///
+    /// # Panics
+    /// - `"Address already registered"` — player has registered before.
+    /// - `"Username must be 3-20 characters"` — invalid username length.
+    pub fn register_player(env: Env, player: Address, username: String) {
+        player.require_auth();
+
+        if storage::is_registered(&env, &player) {
+            panic!("Address already registered");
+        }
+
+        if !(3..=20).contains(&username.len()) {
+            panic!("Username must be 3-20 characters");
+        }
+
+        storage::set_registered(&env, &player);
+        storage::set_username(&env, &player, &username);
+
+        let voucher_value = storage::get_registration_voucher_value(&env);
+        let voucher_id = if voucher_value > 0 {
+            let reward_system = storage::get_reward_system(&env);
+            let minter = env.current_contract_address();
+            let token_id: u128 = env.invoke_contract(
+                &reward_system,
+                &Symbol::new(&env, "mint_voucher"),
+                vec![
+                    &env,
+                    minter.into_val(&env),
+                    player.into_val(&env),
+                    voucher_value.into_val(&env),
+                ],
+            );
+            Some(token_id)
+        } else {
+            None
+        };
+
+        events::emit_player_registered(
+            &env,
+            &events::PlayerRegisteredData {
+                player,
+                username,
+                voucher_id,
+                voucher_value,
+            },
+        );
+    }
+
+    /// Set the TYC value of the welcome voucher minted on registration
+    /// (owner only). Zero disables the voucher.
     ///
-    /// Full implementation will require auth, validate username,
-    /// prevent duplicates, and call the reward system for a registration voucher.
-    pub fn register_player(_env: Env) {
-        // TODO: implement full registration logic
+    /// Deployment constraint: the reward system's `mint_voucher` accepts only
+    /// its admin or its single backend minter, and this contract mints as
+    /// itself. Enabling the voucher means making this contract the backend
+    /// minter, which displaces the current one (tycoon-game or a backend
+    /// service). Enable registration vouchers in tycoon-game or here, not
+    /// both.
+    pub fn set_registration_voucher_value(env: Env, value: u128) {
+        let owner = storage::get_owner(&env);
+        owner.require_auth();
+
+        storage::set_registration_voucher_value(&env, value);
     }
 
-    /// Allow a player to leave a pending (not yet started) game.
+    /// Create a new game lobby and seat the creator as its first player.
     ///
     /// Validates:
-    /// - Game exists.
-    /// - Game status is `Pending`.
-    /// - Caller (`player`) is in `joined_players`.
+    /// - `creator` is registered.
+    /// - `settings.max_players` is between 2 and 8.
+    /// - `Private` games carry a non-empty `private_room_code`; `Public` games
+    ///   carry an empty one.
+    /// - The full pot (`stake_per_player * max_players`) fits in a token amount.
+    /// - `settings.payout_bps` is empty or sums to 10_000 over at most
+    ///   `max_players` places.
     ///
     /// On success:
-    /// - Refunds `stake_per_player` in USDC to the leaving player (if stake > 0).
-    /// - Removes the player from `joined_players`.
-    /// - Decrements `total_staked` by `stake_per_player`.
-    /// - If no players remain, sets game status to `Ended` with current timestamp.
-    /// - Emits `PlayerLeftPending` event always.
-    /// - Emits `PendingGameEnded` event if the lobby is now empty.
+    /// - Transfers `stake_per_player` in USDC from the creator into the contract
+    ///   (if stake > 0).
+    /// - Stores the `Game` (status `Pending`) and its `GameSettings`.
+    /// - Emits `GameCreated`.
+    ///
+    /// Returns the new game ID.
+    ///
+    /// # Panics
+    /// - `"Player is not registered"` — creator has not registered.
+    /// - `"Max players must be between 2 and 8"` — invalid lobby size.
+    /// - `"Private game requires a room code"` — private lobby without a code.
+    /// - `"Public game cannot have a room code"` — public lobby with a code.
+    /// - `"Stake amount too large"` — the full pot would overflow.
+    /// - Payout table errors from `payout::validate_payout_table`.
+    pub fn create_game(
+        env: Env,
+        creator: Address,
+        code: String,
+        mode: GameMode,
+        ai: bool,
+        stake_per_player: u128,
+        settings: GameSettings,
+    ) -> u64 {
+        creator.require_auth();
+
+        if !storage::is_registered(&env, &creator) {
+            panic!("Player is not registered");
+        }
+
+        if settings.max_players < MIN_PLAYERS || settings.max_players > MAX_PLAYERS {
+            panic!("Max players must be between 2 and 8");
+        }
+
+        match mode {
+            GameMode::Private if settings.private_room_code.is_empty() => {
+                panic!("Private game requires a room code");
+            }
+            GameMode::Public if !settings.private_room_code.is_empty() => {
+                panic!("Public game cannot have a room code");
+            }
+            _ => {}
+        }
+
+        // The whole pot must be representable as an i128 token amount
+        let pot_fits = stake_per_player
+            .checked_mul(settings.max_players as u128)
+            .is_some_and(|pot| pot <= i128::MAX as u128);
+        if !pot_fits {
+            panic!("Stake amount too large");
+        }
+
+        payout::validate_payout_table(&settings.payout_bps, settings.max_players);
+
+        // Escrow the creator's stake — transfer from creator to contract
+        if stake_per_player > 0 {
+            let usdc_token = storage::get_usdc_token(&env);
+            let token_client = token::Client::new(&env, &usdc_token);
+            let contract_address = env.current_contract_address();
+            token_client.transfer(&creator, &contract_address, &(stake_per_player as i128));
+        }
+
+        let game_id = storage::next_game_id(&env);
+
+        let mut joined_players = Vec::new(&env);
+        joined_players.push_back(creator.clone());
+
+        let game = Game {
+            id: game_id,
+            code,
+            creator: creator.clone(),
+            status: GameStatus::Pending,
+            winner: None,
+            number_of_players: settings.max_players,
+            joined_players,
+            mode: mode.clone(),
+            ai,
+            stake_per_player,
+            total_staked: stake_per_player,
+            created_at: env.ledger().timestamp(),
+            started_at: 0,
+            ended_at: 0,
+        };
+
+        storage::set_game(&env, &game);
+        storage::set_game_settings(&env, game_id, &settings);
+
+        events::emit_game_created(
+            &env,
+            &events::GameCreatedData {
+                game_id,
+                creator,
+                mode,
+                max_players: settings.max_players,
+                stake_per_player,
+            },
+        );
+
+        game_id
+    }
+
+    /// Join a pending game lobby, paying the game's stake.
+    ///
+    /// Validates:
+    /// - `player` is registered.
+    /// - Game exists and status is `Pending`.
+    /// - Lobby has a free slot (`joined_players` < `max_players`).
+    /// - `room_code` matches `private_room_code` for `Private` games.
+    /// - `player` has not already joined.
+    ///
+    /// On success:
+    /// - Transfers `stake_per_player` in USDC from the player into the contract
+    ///   (if stake > 0).
+    /// - Appends the player to `joined_players` and increments `total_staked`.
+    /// - Emits `PlayerJoined`.
+    /// - Starts the game if `auto_start` is set and the lobby is now full.
     ///
     /// # Panics
+    /// - `"Player is not registered"` — player has not registered.
     /// - `"Game not found"` — game ID does not exist.
     /// - `"Game is not pending"` — game has already started or ended.
-    /// - `"Player is not in this game"` — caller has not joined.
-    pub fn leave_pending_game(env: Env, game_id: u64, player: Address) {
+    /// - `"Game is full"` — no free slots remain.
+    /// - `"Invalid room code"` — wrong code for a private game.
+    /// - `"Player already joined"` — player is already in the lobby.
+    pub fn join_game(env: Env, game_id: u64, player: Address, room_code: String) {
         player.require_auth();
 
+        if !storage::is_registered(&env, &player) {
+            panic!("Player is not registered");
+        }
+
         let mut game = storage::get_game(&env, game_id).unwrap_or_else(|| panic!("Game not found"));
 
         if !matches!(game.status, GameStatus::Pending) {
             panic!("Game is not pending");
         }
 
-        // Find and remove the player from joined_players
-        let mut new_players: Vec<Address> = Vec::new(&env);
-        let mut found = false;
+        let settings = storage::get_game_settings(&env, game_id)
+            .unwrap_or_else(|| panic!("Game settings not found"));
 
-        for p in game.joined_players.iter() {
-            if p == player {
-                found = true;
-            } else {
-                new_players.push_back(p);
-            }
+        if game.joined_players.len() >= settings.max_players {
+            panic!("Game is full");
         }
 
-        if !found {
-            panic!("Player is not in this game");
+        if matches!(game.mode, GameMode::Private) && room_code != settings.private_room_code {
+            panic!("Invalid room code");
+        }
+
+        if game.joined_players.contains(&player) {
+            panic!("Player already joined");
         }
 
-        // Refund stake if applicable — transfer from contract to player
+        // Collect stake — transfer from player to contract
         if game.stake_per_player > 0 {
             let usdc_token = storage::get_usdc_token(&env);
             let token_client = token::Client::new(&env, &usdc_token);
             let contract_address = env.current_contract_address();
-            token_client.transfer(&contract_address, &player, &(game.stake_per_player as i128));
+            token_client.transfer(&player, &contract_address, &(game.stake_per_player as i128));
         }
 
-        // Update game state
-        game.total_staked = game.total_staked.saturating_sub(game.stake_per_player);
-        game.joined_players = new_players;
+        game.joined_players.push_back(player.clone());
+        game.total_staked += game.stake_per_player;
 
-        let remaining = game.joined_players.len() as u32;
+        storage::set_game(&env, &game);
 
-        // If no players remain, end the game automatically
-        if remaining == 0 {
-            game.status = GameStatus::Ended;
-            game.ended_at = env.ledger().timestamp();
+        events::emit_player_joined(
+            &env,
+            &events::PlayerJoinedData {
+                game_id,
+                player,
+                stake_paid: game.stake_per_player,
+                joined_players: game.joined_players.len(),
+            },
+        );
+
+        if settings.auto_start && game.joined_players.len() >= game.number_of_players {
+            Self::begin_game(&env, &mut game);
         }
+    }
 
-        storage::set_game(&env, &game);
+    /// Start a pending game, fixing its turn order.
+    ///
+    /// Callable by the game's creator or the backend game controller.
+    ///
+    /// Validates:
+    /// - Game exists and status is `Pending`.
+    /// - At least 2 players have joined.
+    ///
+    /// On success:
+    /// - Shuffles `joined_players` into the stored turn order.
+    /// - Sets status to `Ongoing` and `started_at` to the current timestamp.
+    /// - Emits `GameStarted`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Unauthorized: caller must be creator or backend game controller"`
+    /// - `"Game is not pending"` — game has already started or ended.
+    /// - `"Not enough players to start"` — fewer than 2 players joined.
+    pub fn start_game(env: Env, game_id: u64, caller: Address) {
+        caller.require_auth();
 
-        // Emit PlayerLeftPending
-        events::emit_player_left_pending(
+        let mut game = storage::get_game(&env, game_id).unwrap_or_else(|| panic!("Game not found"));
+
+        let is_creator = caller == game.creator;
+        let is_backend_controller =
+            storage::get_backend_game_controller(&env).is_some_and(|c| c == caller);
+        if !is_creator && !is_backend_controller {
+            panic!("Unauthorized: caller must be creator or backend game controller");
+        }
+
+        if !matches!(game.status, GameStatus::Pending) {
+            panic!("Game is not pending");
+        }
+
+        if game.joined_players.len() < MIN_PLAYERS {
+            panic!("Not enough players to start");
+        }
+
+        Self::begin_game(&env, &mut game);
+    }
+
+    /// Settle an ongoing game, splitting the escrowed pot across placements.
+    ///
+    /// Callable by the owner or the backend game controller. `placements` is
+    /// the ranked finishing order (1st place first); 1st place is recorded as
+    /// the winner.
+    ///
+    /// Validates:
+    /// - Game exists and status is `Ongoing`.
+    /// - `placements` covers every paid place in `GameSettings.payout_bps`.
+    /// - Every placed player is in `joined_players`, with no duplicates.
+    ///
+    /// If a dispute window is configured, the result is only reported: the
+    /// status becomes `Reported`, a `PendingResult` is stored and
+    /// `ResultReported` is emitted. Payout happens in `finalize_result` or
+    /// `resolve_dispute`.
+    ///
+    /// Otherwise, on success:
+    /// - Accrues the protocol fee (`ProtocolFeeBps` of `total_staked`) to the
+    ///   USDC treasury balance.
+    /// - Transfers each place its share of the remaining pot in USDC (see
+    ///   `payout::split_pot`); rounding dust goes to 1st place.
+    /// - Stores `placements`, sets status to `Ended` and `total_staked` to
+    ///   zero, records `winner` and `ended_at`.
+    /// - Emits `GameSettled`.
+    ///
+    /// # Panics
+    /// - `"Unauthorized: caller must be owner or backend game controller"`
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Not enough placements for payout table"` — paid places missing
+    ///   (places beyond the number of joined players are not required).
+    /// - `"Placement is not in this game"` — a placed player never joined.
+    /// - `"Duplicate placement"` — a player is placed twice.
+    pub fn end_game(env: Env, game_id: u64, caller: Address, placements: Vec<Address>) {
+        caller.require_auth();
+
+        let owner = storage::get_owner(&env);
+        let is_owner = caller == owner;
+        let is_backend_controller =
+            storage::get_backend_game_controller(&env).is_some_and(|c| c == caller);
+        if !is_owner && !is_backend_controller {
+            panic!("Unauthorized: caller must be owner or backend game controller");
+        }
+
+        let mut game = storage::get_game(&env, game_id).unwrap_or_else(|| panic!("Game not found"));
+
+        if !matches!(game.status, GameStatus::Ongoing) {
+            panic!("Game is not ongoing");
+        }
+
+        Self::validate_placements(&env, &game, &placements);
+        Self::report_result(&env, &mut game, &placements);
+    }
+
+    /// Register the ed25519 public key whose signed results can settle games
+    /// through `submit_signed_result` (owner only).
+    ///
+    /// Emits `ResultSignerUpdated`.
+    pub fn set_result_signer(env: Env, public_key: BytesN<32>) {
+        let owner = storage::get_owner(&env);
+        owner.require_auth();
+
+        let old_signer = storage::get_result_signer(&env);
+        storage::set_result_signer(&env, &public_key);
+
+        events::emit_result_signer_updated(
             &env,
-            &events::PlayerLeftPendingData {
+            &events::ResultSignerUpdatedData {
+                old_signer,
+                new_signer: public_key,
+            },
+        );
+    }
+
+    /// Settle an ongoing game from a result signed by the result signer.
+    /// Callable by anyone; the signature, not the caller, authorizes it.
+    ///
+    /// The signed message is the XDR of a `GameResultPayload` binding this
+    /// contract, `game_id`, `placements`, `state_hash` and `nonce`.
+    ///
+    /// Validates:
+    /// - Game exists and status is `Ongoing`.
+    /// - `nonce` is greater than the last nonce accepted for the game.
+    /// - `placements` passes the same checks as `end_game`.
+    /// - `signature` verifies against the registered result signer.
+    ///
+    /// On success:
+    /// - Records `nonce` and `state_hash` for the game.
+    /// - Emits `SignedResultAccepted`, then reports or settles the result as
+    ///   `end_game` does.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Result signer not set"`
+    /// - `"Nonce already used"` — `nonce` is not above the last accepted one.
+    /// - Placement panics from `end_game`.
+    /// - Traps if the signature is invalid.
+    pub fn submit_signed_result(
+        env: Env,
+        relayer: Address,
+        game_id: u64,
+        placements: Vec<Address>,
+        state_hash: BytesN<32>,
+        nonce: u64,
+        signature: BytesN<64>,
+    ) {
+        relayer.require_auth();
+
+        let mut game = Self::load_ongoing_game(&env, game_id);
+        let public_key =
+            storage::get_result_signer(&env).unwrap_or_else(|| panic!("Result signer not set"));
+
+        if nonce <= storage::get_result_nonce(&env, game_id) {
+            panic!("Nonce already used");
+        }
+
+        Self::validate_placements(&env, &game, &placements);
+
+        let payload = GameResultPayload {
+            contract: env.current_contract_address(),
+            game_id,
+            placements: placements.clone(),
+            state_hash: state_hash.clone(),
+            nonce,
+        };
+        attestation::verify(&env, &public_key, &payload, &signature);
+
+        storage::set_result_nonce(&env, game_id, nonce);
+        storage::set_result_state_hash(&env, game_id, &state_hash);
+
+        events::emit_signed_result_accepted(
+            &env,
+            &events::SignedResultAcceptedData {
                 game_id,
-                player: player.clone(),
-                stake_refunded: game.stake_per_player,
-                remaining_players: remaining,
+                relayer,
+                state_hash,
+                nonce,
             },
         );
 
-        // Emit PendingGameEnded if lobby is now empty
-        if remaining == 0 {
-            events::emit_pending_game_ended(&env, &events::PendingGameEndedData { game_id });
+        Self::report_result(&env, &mut game, &placements);
+    }
+
+    /// Configure the dispute window and bond applied to newly reported
+    /// results (owner only). A zero `window` settles results immediately.
+    pub fn set_dispute_config(env: Env, window: u64, bond: u128) {
+        let owner = storage::get_owner(&env);
+        owner.require_auth();
+
+        if bond > i128::MAX as u128 {
+            panic!("Bond amount too large");
         }
+
+        storage::set_dispute_window(&env, window);
+        storage::set_dispute_bond(&env, bond);
     }
 
-    // -----------------------------------------------------------------------
-    // View functions
-    // -----------------------------------------------------------------------
+    /// Set the arbiter allowed, alongside the owner, to resolve disputes
+    /// (owner only).
+    pub fn set_arbiter(env: Env, arbiter: Address) {
+        let owner = storage::get_owner(&env);
+        owner.require_auth();
 
-    /// Returns the owner address stored during initialization.
-    pub fn get_owner(env: Env) -> Address {
-        storage::get_owner(&env)
+        storage::set_arbiter(&env, &arbiter);
     }
 
-    /// Returns the reward system contract address stored during initialization.
-    pub fn get_reward_system(env: Env) -> Address {
-        storage::get_reward_system(&env)
+    /// Set the tycoon-collectibles contract whose JailFree perks can be
+    /// burned to leave jail (owner only).
+    pub fn set_collectibles_contract(env: Env, collectibles: Address) {
+        let owner = storage::get_owner(&env);
+        owner.require_auth();
+
+        storage::set_collectibles_contract(&env, &collectibles);
     }
 
-    /// Returns true if the given address has been registered as a player.
-    pub fn is_registered(env: Env, address: Address) -> bool {
-        storage::is_registered(&env, &address)
+    /// Dispute a reported result while its window is open, posting the
+    /// dispute bond in USDC. Callable by any player who joined the game.
+    ///
+    /// A disputed result no longer finalizes on its own; the owner or
+    /// arbiter must resolve it with `resolve_dispute`.
+    ///
+    /// Emits `ResultDisputed`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game result is not reported"` — no result awaits finalization.
+    /// - `"Player is not in this game"` — `player` never joined.
+    /// - `"Dispute window has closed"`
+    /// - `"Result already disputed"`
+    pub fn dispute_result(env: Env, game_id: u64, player: Address) {
+        player.require_auth();
+
+        let (game, mut pending) = Self::load_reported_game(&env, game_id);
+
+        if !game.joined_players.contains(&player) {
+            panic!("Player is not in this game");
+        }
+        if env.ledger().timestamp() >= pending.finalizes_at {
+            panic!("Dispute window has closed");
+        }
+        if pending.disputed_by.is_some() {
+            panic!("Result already disputed");
+        }
+
+        if pending.bond > 0 {
+            let token_client = token::Client::new(&env, &storage::get_usdc_token(&env));
+            let contract_address = env.current_contract_address();
+            token_client.transfer(&player, &contract_address, &(pending.bond as i128));
+        }
+
+        pending.disputed_by = Some(player.clone());
+        storage::set_pending_result(&env, game_id, &pending);
+
+        events::emit_result_disputed(
+            &env,
+            &events::ResultDisputedData {
+                game_id,
+                disputed_by: player,
+                bond: pending.bond,
+            },
+        );
     }
 
-    /// Retrieves a game by ID. Returns `None` if not found.
-    pub fn get_game(env: Env, game_id: u64) -> Option<Game> {
-        storage::get_game(&env, game_id)
+    /// Resolve a disputed result (owner or arbiter only).
+    ///
+    /// With `placements` of `None` the reported result is upheld and the
+    /// dispute bond is forfeited to the USDC treasury. Otherwise the given
+    /// placements override the reported ones and the bond is returned to the
+    /// disputer. Either way the game then settles as `end_game` does.
+    ///
+    /// Emits `DisputeResolved`, then `GameSettled`.
+    ///
+    /// # Panics
+    /// - `"Unauthorized: caller must be owner or arbiter"`
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game result is not reported"` — no result awaits finalization.
+    /// - `"Result is not disputed"`
+    /// - Placement panics from `end_game` for overriding placements.
+    pub fn resolve_dispute(
+        env: Env,
+        game_id: u64,
+        caller: Address,
+        placements: Option<Vec<Address>>,
+    ) {
+        caller.require_auth();
+
+        let is_owner = caller == storage::get_owner(&env);
+        let is_arbiter = storage::get_arbiter(&env).is_some_and(|a| a == caller);
+        if !is_owner && !is_arbiter {
+            panic!("Unauthorized: caller must be owner or arbiter");
+        }
+
+        let (mut game, pending) = Self::load_reported_game(&env, game_id);
+        let disputer = pending
+            .disputed_by
+            .clone()
+            .unwrap_or_else(|| panic!("Result is not disputed"));
+
+        let upheld = placements.is_none();
+        let placements = match placements {
+            Some(placements) => {
+                Self::validate_placements(&env, &game, &placements);
+                if pending.bond > 0 {
+                    let token_client = token::Client::new(&env, &storage::get_usdc_token(&env));
+                    let contract_address = env.current_contract_address();
+                    token_client.transfer(&contract_address, &disputer, &(pending.bond as i128));
+                }
+                placements
+            }
+            None => {
+                if pending.bond > 0 {
+                    let usdc_token = storage::get_usdc_token(&env);
+                    let treasury = storage::get_treasury_balance(&env, &usdc_token);
+                    storage::set_treasury_balance(&env, &usdc_token, treasury + pending.bond);
+                }
+                pending.placements
+            }
+        };
+
+        events::emit_dispute_resolved(
+            &env,
+            &events::DisputeResolvedData {
+                game_id,
+                resolved_by: caller,
+                upheld,
+                placements: placements.clone(),
+            },
+        );
+
+        storage::remove_pending_result(&env, game_id);
+        Self::settle_game(&env, &mut game, &placements);
     }
 
-    /// Retrieves settings for a game by ID. Returns `None` if not found.
-    pub fn get_game_settings(env: Env, game_id: u64) -> Option<GameSettings> {
-        storage::get_game_settings(&env, game_id)
+    /// Settle an undisputed reported result once its dispute window has
+    /// closed. Callable by anyone.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game result is not reported"` — no result awaits finalization.
+    /// - `"Result is disputed"` — must go through `resolve_dispute`.
+    /// - `"Dispute window is still open"`
+    pub fn finalize_result(env: Env, game_id: u64) {
+        let (mut game, pending) = Self::load_reported_game(&env, game_id);
+
+        if pending.disputed_by.is_some() {
+            panic!("Result is disputed");
+        }
+        if env.ledger().timestamp() < pending.finalizes_at {
+            panic!("Dispute window is still open");
+        }
+
+        storage::remove_pending_result(&env, game_id);
+        Self::settle_game(&env, &mut game, &pending.placements);
+    }
+
+    /// Set how long after `started_at` a game may stay unsettled before its
+    /// players can refund it (owner only). Applies to games started after
+    /// the change.
+    ///
+    /// # Panics
+    /// - `"Settlement timeout must be non-zero"`
+    pub fn set_settlement_timeout(env: Env, timeout: u64) {
+        let owner = storage::get_owner(&env);
+        owner.require_auth();
+
+        if timeout == 0 {
+            panic!("Settlement timeout must be non-zero");
+        }
+
+        storage::set_settlement_timeout(&env, timeout);
+    }
+
+    /// Set the protocol fee taken from each settled pot (owner only).
+    ///
+    /// Emits `ProtocolFeeUpdated`.
+    ///
+    /// # Panics
+    /// - `"Fee exceeds maximum"` — `fee_bps` is above 1000 (10%).
+    pub fn set_protocol_fee(env: Env, fee_bps: u32) {
+        let owner = storage::get_owner(&env);
+        owner.require_auth();
+
+        if fee_bps > MAX_PROTOCOL_FEE_BPS {
+            panic!("Fee exceeds maximum");
+        }
+
+        let old_fee_bps = storage::get_protocol_fee_bps(&env);
+        storage::set_protocol_fee_bps(&env, fee_bps);
+
+        events::emit_protocol_fee_updated(
+            &env,
+            &events::ProtocolFeeUpdatedData {
+                old_fee_bps,
+                new_fee_bps: fee_bps,
+            },
+        );
+    }
+
+    /// Withdraw accrued protocol fees for `token` to `to` (owner only).
+    ///
+    /// Only the tracked treasury balance can be withdrawn — escrowed stakes
+    /// of open games are never touched.
+    ///
+    /// Emits `TreasuryWithdrawn`.
+    ///
+    /// # Panics
+    /// - `"Insufficient treasury balance"` — `amount` exceeds accrued fees.
+    pub fn withdraw_treasury(env: Env, token: Address, to: Address, amount: u128) {
+        let owner = storage::get_owner(&env);
+        owner.require_auth();
+
+        let balance = storage::get_treasury_balance(&env, &token);
+        if amount > balance {
+            panic!("Insufficient treasury balance");
+        }
+
+        storage::set_treasury_balance(&env, &token, balance - amount);
+
+        let token_client = token::Client::new(&env, &token);
+        let contract_address = env.current_contract_address();
+        token_client.transfer(&contract_address, &to, &(amount as i128));
+
+        events::emit_treasury_withdrawn(&env, &events::TreasuryWithdrawnData { token, to, amount });
+    }
+
+    /// Set the backend game controller address (owner only).
+    pub fn set_backend_game_controller(env: Env, new_controller: Address) {
+        let owner = storage::get_owner(&env);
+        owner.require_auth();
+
+        storage::set_backend_game_controller(&env, &new_controller);
+    }
+
+    /// Allow a player to leave a pending (not yet started) game.
+    ///
+    /// Validates:
+    /// - Game exists.
+    /// - Game status is `Pending`.
+    /// - Caller (`player`) is in `joined_players`.
+    ///
+    /// On success:
+    /// - Refunds `stake_per_player` in USDC to the leaving player (if stake > 0).
+    /// - Removes the player from `joined_players`.
+    /// - Decrements `total_staked` by `stake_per_player`.
+    /// - If no players remain, sets game status to `Ended` with current timestamp.
+    /// - Emits `PlayerLeftPending` event always.
+    /// - Emits `PendingGameEnded` event if the lobby is now empty.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not pending"` — game has already started or ended.
+    /// - `"Player is not in this game"` — caller has not joined.
+    pub fn leave_pending_game(env: Env, game_id: u64, player: Address) {
+        player.require_auth();
+
+        let mut game = storage::get_game(&env, game_id).unwrap_or_else(|| panic!("Game not found"));
+
+        if !matches!(game.status, GameStatus::Pending) {
+            panic!("Game is not pending");
+        }
+
+        // Find and remove the player from joined_players
+        let mut new_players: Vec<Address> = Vec::new(&env);
+        let mut found = false;
+
+        for p in game.joined_players.iter() {
+            if p == player {
+                found = true;
+            } else {
+                new_players.push_back(p);
+            }
+        }
+
+        if !found {
+            panic!("Player is not in this game");
+        }
+
+        Self::refund_stake(&env, &game, &player);
+
+        // Update game state
+        game.total_staked = game.total_staked.saturating_sub(game.stake_per_player);
+        game.joined_players = new_players;
+
+        let remaining = game.joined_players.len();
+
+        // If no players remain, end the game automatically
+        if remaining == 0 {
+            game.status = GameStatus::Ended;
+            game.ended_at = env.ledger().timestamp();
+        }
+
+        storage::set_game(&env, &game);
+
+        // Emit PlayerLeftPending
+        events::emit_player_left_pending(
+            &env,
+            &events::PlayerLeftPendingData {
+                game_id,
+                player: player.clone(),
+                stake_refunded: game.stake_per_player,
+                remaining_players: remaining,
+            },
+        );
+
+        // Emit PendingGameEnded if lobby is now empty
+        if remaining == 0 {
+            events::emit_pending_game_ended(&env, &events::PendingGameEndedData { game_id });
+        }
+    }
+
+    /// Cancel a pending game, refunding every joined player.
+    ///
+    /// Only the game's creator may cancel, and only before the game starts.
+    ///
+    /// On success:
+    /// - Refunds `stake_per_player` in USDC to each player in `joined_players`.
+    /// - Sets status to `Ended`, `total_staked` to zero and `ended_at`.
+    /// - Emits `GameCancelled`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Only the creator can cancel"` — caller is not the creator.
+    /// - `"Game is not pending"` — game has already started or ended.
+    pub fn cancel_game(env: Env, game_id: u64, creator: Address) {
+        creator.require_auth();
+
+        let mut game = storage::get_game(&env, game_id).unwrap_or_else(|| panic!("Game not found"));
+
+        if creator != game.creator {
+            panic!("Only the creator can cancel");
+        }
+
+        if !matches!(game.status, GameStatus::Pending) {
+            panic!("Game is not pending");
+        }
+
+        let data = Self::close_pending_game(&env, &mut game);
+        events::emit_game_cancelled(&env, &data);
+    }
+
+    /// Expire a pending game whose lobby timeout has elapsed, refunding every
+    /// joined player. Callable by anyone.
+    ///
+    /// A lobby expires once `created_at + GameSettings.lobby_timeout` has
+    /// passed. Games with a `lobby_timeout` of zero never expire.
+    ///
+    /// On success:
+    /// - Refunds `stake_per_player` in USDC to each player in `joined_players`.
+    /// - Sets status to `Ended`, `total_staked` to zero and `ended_at`.
+    /// - Emits `GameExpired`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not pending"` — game has already started or ended.
+    /// - `"Lobby has not expired"` — timeout disabled or not yet reached.
+    pub fn expire_game(env: Env, game_id: u64) {
+        let mut game = storage::get_game(&env, game_id).unwrap_or_else(|| panic!("Game not found"));
+
+        if !matches!(game.status, GameStatus::Pending) {
+            panic!("Game is not pending");
+        }
+
+        let settings = storage::get_game_settings(&env, game_id)
+            .unwrap_or_else(|| panic!("Game settings not found"));
+
+        let expires_at = game.created_at.saturating_add(settings.lobby_timeout);
+        if settings.lobby_timeout == 0 || env.ledger().timestamp() < expires_at {
+            panic!("Lobby has not expired");
+        }
+
+        let data = Self::close_pending_game(&env, &mut game);
+        events::emit_game_expired(&env, &data);
+    }
+
+    /// Refund an ongoing game, or a reported game whose result is disputed
+    /// and unresolved, that was not settled before its settlement deadline.
+    /// Callable by any player who joined the game. An undisputed reported
+    /// result must go through `finalize_result` instead.
+    ///
+    /// The deadline is fixed when the game starts, at `started_at` plus the
+    /// settlement timeout. `total_staked` is split evenly across
+    /// `joined_players`; rounding dust goes to the first player. No protocol
+    /// fee is taken.
+    ///
+    /// On success:
+    /// - Transfers each joined player their share in USDC, and returns any
+    ///   dispute bond to its poster.
+    /// - Sets status to `Ended`, `total_staked` to zero and `ended_at`.
+    /// - Emits `GameAbandoned`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Undisputed result must be finalized"` — reported result was not disputed.
+    /// - `"Player is not in this game"` — `player` never joined.
+    /// - `"Settlement deadline has not passed"`
+    pub fn claim_settlement_refund(env: Env, game_id: u64, player: Address) {
+        player.require_auth();
+
+        let mut game = storage::get_game(&env, game_id).unwrap_or_else(|| panic!("Game not found"));
+        match game.status {
+            GameStatus::Ongoing => {}
+            GameStatus::Reported => {
+                // Only a dispute nobody resolved counts as "not settled"
+                let disputed = storage::get_pending_result(&env, game_id)
+                    .is_some_and(|pending| pending.disputed_by.is_some());
+                if !disputed {
+                    panic!("Undisputed result must be finalized");
+                }
+            }
+            _ => panic!("Game is not ongoing"),
+        }
+
+        if !game.joined_players.contains(&player) {
+            panic!("Player is not in this game");
+        }
+
+        let deadline = storage::get_settlement_deadline(&env, game_id)
+            .unwrap_or_else(|| panic!("Settlement deadline not found"));
+        if env.ledger().timestamp() < deadline {
+            panic!("Settlement deadline has not passed");
+        }
+
+        let total_refunded = game.total_staked;
+        let players = game.joined_players.len();
+        let share = total_refunded / players as u128;
+        let dust = total_refunded % players as u128;
+
+        let token_client = token::Client::new(&env, &storage::get_usdc_token(&env));
+        let contract_address = env.current_contract_address();
+
+        if let Some(pending) = storage::get_pending_result(&env, game_id) {
+            if let Some(disputer) = pending.disputed_by {
+                if pending.bond > 0 {
+                    token_client.transfer(&contract_address, &disputer, &(pending.bond as i128));
+                }
+            }
+            storage::remove_pending_result(&env, game_id);
+        }
+
+        for (i, joined) in game.joined_players.iter().enumerate() {
+            let amount = if i == 0 { share + dust } else { share };
+            if amount > 0 {
+                token_client.transfer(&contract_address, &joined, &(amount as i128));
+            }
+        }
+
+        game.status = GameStatus::Ended;
+        game.total_staked = 0;
+        game.ended_at = env.ledger().timestamp();
+        storage::set_game(&env, &game);
+
+        events::emit_game_abandoned(
+            &env,
+            &events::GameAbandonedData {
+                game_id,
+                triggered_by: player,
+                players_refunded: players,
+                total_refunded,
+            },
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Turns and timeouts
+    // -----------------------------------------------------------------------
+
+    /// End the current turn, passing play to the next player in turn order.
+    /// The player must have called `move_token` this turn (a jailed player's
+    /// failed doubles attempt counts).
+    ///
+    /// Records `player`'s last action timestamp and emits `TurnStarted` for
+    /// the next player.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Not your turn"` — `player` is not the current player.
+    /// - `"Must move before ending turn"`
+    pub fn end_turn(env: Env, game_id: u64, player: Address) {
+        player.require_auth();
+
+        let game = Self::load_ongoing_game(&env, game_id);
+        let mut state = Self::require_current_player(&env, game_id, &player);
+        if !state.moved {
+            panic!("Must move before ending turn");
+        }
+        Self::record_action(&env, game_id, &player);
+
+        let turn_order = storage::get_turn_order(&env, game_id).unwrap_or(Vec::new(&env));
+        let next = (state.current + 1) % turn_order.len();
+        Self::start_turn(&env, &game, &turn_order, &mut state, next);
+    }
+
+    /// Eliminate the current player once their turn deadline has passed.
+    /// Callable by any other player still in the game.
+    ///
+    /// The deadline is `TurnState.turn_started_at + GameSettings.turn_timeout`.
+    /// The idle player's stake stays in the pot and is paid out to the
+    /// remaining players when the game settles. If only one player remains,
+    /// the game settles immediately with that player in 1st place and the
+    /// others ranked by reverse elimination order.
+    ///
+    /// On success:
+    /// - Removes the idle player from the turn order and records them as
+    ///   eliminated.
+    /// - Returns their properties to the bank and voids their auction bids.
+    /// - Emits `PlayerTimedOut`, then `TurnStarted` for the next player or
+    ///   `GameSettled` if the game is over.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Claimer is not in this game"` — `claimer` is not a remaining player.
+    /// - `"Cannot claim timeout against yourself"`
+    /// - `"Turn has not timed out"` — timeout disabled or deadline not reached.
+    pub fn claim_timeout(env: Env, game_id: u64, claimer: Address) {
+        claimer.require_auth();
+
+        let mut game = Self::load_ongoing_game(&env, game_id);
+        let settings = storage::get_game_settings(&env, game_id)
+            .unwrap_or_else(|| panic!("Game settings not found"));
+        let state = storage::get_turn_state(&env, game_id)
+            .unwrap_or_else(|| panic!("Turn state not found"));
+        let turn_order = storage::get_turn_order(&env, game_id).unwrap_or(Vec::new(&env));
+
+        if !turn_order.contains(&claimer) {
+            panic!("Claimer is not in this game");
+        }
+
+        let idle_player = turn_order
+            .get(state.current)
+            .unwrap_or_else(|| panic!("Current player not found"));
+        if idle_player == claimer {
+            panic!("Cannot claim timeout against yourself");
+        }
+
+        let deadline = state.turn_started_at.saturating_add(settings.turn_timeout);
+        if settings.turn_timeout == 0 || env.ledger().timestamp() < deadline {
+            panic!("Turn has not timed out");
+        }
+
+        events::emit_player_timed_out(
+            &env,
+            &events::PlayerTimedOutData {
+                game_id,
+                player: idle_player.clone(),
+                claimed_by: claimer,
+                turn_number: state.turn_number,
+            },
+        );
+
+        Self::eliminate_player(&env, &mut game, &idle_player);
+    }
+
+    // -----------------------------------------------------------------------
+    // Dice
+    // -----------------------------------------------------------------------
+
+    /// Commit to a dice seed for the current turn.
+    ///
+    /// Callable by the current player and the backend game controller, each
+    /// once per turn. `commitment` must be `sha256(seed)` for the seed later
+    /// passed to `reveal_dice`. The first commitment opens the round and
+    /// sets its reveal deadline `DICE_REVEAL_WINDOW` seconds later.
+    ///
+    /// Emits `DiceCommitted`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Unauthorized: caller must be current player or backend game controller"`
+    /// - `"Dice round has expired"` — the reveal deadline has passed.
+    /// - `"Dice already committed"` — `committer` already committed this turn.
+    pub fn commit_dice(env: Env, game_id: u64, committer: Address, commitment: BytesN<32>) {
+        committer.require_auth();
+
+        Self::load_ongoing_game(&env, game_id);
+        let state = storage::get_turn_state(&env, game_id)
+            .unwrap_or_else(|| panic!("Turn state not found"));
+        let is_player = Self::is_dice_player(&env, game_id, &state, &committer);
+
+        let now = env.ledger().timestamp();
+        let mut round =
+            storage::get_dice_round(&env, game_id, state.turn_number).unwrap_or(DiceRound {
+                player_commit: None,
+                backend_commit: None,
+                player_seed: None,
+                backend_seed: None,
+                reveal_deadline: now.saturating_add(DICE_REVEAL_WINDOW),
+                die1: 0,
+                die2: 0,
+                fallback: false,
+                forfeited: false,
+            });
+        if now >= round.reveal_deadline {
+            panic!("Dice round has expired");
+        }
+
+        let slot = if is_player {
+            &mut round.player_commit
+        } else {
+            &mut round.backend_commit
+        };
+        if slot.is_some() {
+            panic!("Dice already committed");
+        }
+        *slot = Some(commitment.clone());
+        storage::set_dice_round(&env, game_id, state.turn_number, &round);
+
+        events::emit_dice_committed(
+            &env,
+            &events::DiceCommittedData {
+                game_id,
+                turn_number: state.turn_number,
+                committer,
+                commitment,
+                reveal_deadline: round.reveal_deadline,
+            },
+        );
+    }
+
+    /// Reveal the seed behind a dice commitment for the current turn.
+    ///
+    /// The backend reveals first, so it never sees the outcome before it is
+    /// fixed. The player's reveal then derives the roll as described in
+    /// `dice::derive_roll`, stores it on the round, and emits `DiceRolled`.
+    ///
+    /// Emits `DiceRevealed`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Unauthorized: caller must be current player or backend game controller"`
+    /// - `"Dice not committed"` — both commitments are not in yet.
+    /// - `"Dice round has expired"` — the reveal deadline has passed.
+    /// - `"Backend must reveal first"` — the player revealed before the backend.
+    /// - `"Dice already revealed"` — `revealer` already revealed this turn.
+    /// - `"Seed does not match commitment"`
+    pub fn reveal_dice(env: Env, game_id: u64, revealer: Address, seed: BytesN<32>) {
+        revealer.require_auth();
+
+        Self::load_ongoing_game(&env, game_id);
+        let state = storage::get_turn_state(&env, game_id)
+            .unwrap_or_else(|| panic!("Turn state not found"));
+        let is_player = Self::is_dice_player(&env, game_id, &state, &revealer);
+
+        let mut round = storage::get_dice_round(&env, game_id, state.turn_number)
+            .unwrap_or_else(|| panic!("Dice not committed"));
+        let (Some(player_commit), Some(backend_commit)) =
+            (round.player_commit.clone(), round.backend_commit.clone())
+        else {
+            panic!("Dice not committed");
+        };
+        if env.ledger().timestamp() >= round.reveal_deadline {
+            panic!("Dice round has expired");
+        }
+        if is_player && round.backend_seed.is_none() {
+            panic!("Backend must reveal first");
+        }
+
+        let (commit, slot) = if is_player {
+            (player_commit, &mut round.player_seed)
+        } else {
+            (backend_commit, &mut round.backend_seed)
+        };
+        if slot.is_some() {
+            panic!("Dice already revealed");
+        }
+        if dice::commitment(&env, &seed) != commit {
+            panic!("Seed does not match commitment");
+        }
+        *slot = Some(seed.clone());
+
+        events::emit_dice_revealed(
+            &env,
+            &events::DiceRevealedData {
+                game_id,
+                turn_number: state.turn_number,
+                revealer,
+                seed,
+            },
+        );
+
+        if let (Some(player_seed), Some(backend_seed)) =
+            (round.player_seed.clone(), round.backend_seed.clone())
+        {
+            let (die1, die2) = dice::derive_roll(&env, &player_seed, &backend_seed);
+            Self::record_roll(&env, game_id, &state, &mut round, die1, die2, false);
+        } else {
+            storage::set_dice_round(&env, game_id, state.turn_number, &round);
+        }
+    }
+
+    /// Settle the current turn's dice once the reveal deadline passes
+    /// without both seeds revealed. Callable by anyone.
+    ///
+    /// The player reveals last and can already compute the roll, so
+    /// withholding must not earn a reroll:
+    /// - The backend revealed: the player forfeits this turn's move. A
+    ///   jailed player's forfeit counts as a failed doubles attempt.
+    /// - The backend did not reveal (or never committed): nobody knew the
+    ///   outcome, so the ledger PRNG rolls.
+    ///
+    /// Emits `DiceRolled`, or `DiceForfeited` when the player forfeits.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Dice not committed"` — no dice round was opened this turn.
+    /// - `"Dice already rolled"`
+    /// - `"Reveal deadline has not passed"`
+    pub fn resolve_dice_timeout(env: Env, game_id: u64) {
+        Self::load_ongoing_game(&env, game_id);
+        let state = storage::get_turn_state(&env, game_id)
+            .unwrap_or_else(|| panic!("Turn state not found"));
+
+        let mut round = storage::get_dice_round(&env, game_id, state.turn_number)
+            .unwrap_or_else(|| panic!("Dice not committed"));
+        if round.die1 != 0 || round.forfeited {
+            panic!("Dice already rolled");
+        }
+        if env.ledger().timestamp() < round.reveal_deadline {
+            panic!("Reveal deadline has not passed");
+        }
+
+        if round.backend_seed.is_some() {
+            Self::forfeit_roll(&env, game_id, state, &mut round);
+            return;
+        }
+
+        let (die1, die2) = dice::fallback_roll(&env);
+        Self::record_roll(&env, game_id, &state, &mut round, die1, die2, true);
+    }
+
+    // -----------------------------------------------------------------------
+    // Board
+    // -----------------------------------------------------------------------
+
+    /// Move the current player's token by this turn's dice roll.
+    ///
+    /// Passing or landing on Go pays `board::GO_SALARY`. Landing on another
+    /// player's property charges rent (see `rent_due`), and landing on a tax
+    /// square pays the tax to the bank, both from in-game cash. A player who
+    /// cannot cover the charge even after selling houses and mortgaging goes
+    /// bankrupt (see `bankrupt_player`) and is eliminated.
+    ///
+    /// A jailed player only moves if they roll doubles. Otherwise the roll
+    /// is used up in jail, and after `board::MAX_JAIL_ATTEMPTS` failed
+    /// attempts bail is charged and the player moves by the roll anyway.
+    /// Landing on Go To Jail sends the player to jail.
+    ///
+    /// Emits `PlayerMoved`, then `RentPaid` or `TaxPaid` if charged, or
+    /// `PlayerBankrupt` if the player could not pay. Also emits
+    /// `JailReleased` and `PlayerJailed` on entering and leaving jail.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Not your turn"` — `player` is not the current player.
+    /// - `"Already moved this turn"`
+    /// - `"Dice not rolled"` — this turn's dice round has no outcome yet.
+    pub fn move_token(env: Env, game_id: u64, player: Address) {
+        player.require_auth();
+
+        Self::load_ongoing_game(&env, game_id);
+        let mut state = Self::require_current_player(&env, game_id, &player);
+        if state.moved {
+            panic!("Already moved this turn");
+        }
+
+        let round = storage::get_dice_round(&env, game_id, state.turn_number)
+            .unwrap_or_else(|| panic!("Dice not rolled"));
+        if round.die1 == 0 {
+            panic!("Dice not rolled");
+        }
+        let dice_total = round.die1 + round.die2;
+
+        if let Some(attempts) = storage::get_jail_attempts(&env, game_id, &player) {
+            if round.die1 == round.die2 {
+                Self::release_from_jail(&env, game_id, &player, JailRelease::Doubles);
+            } else if attempts + 1 < board::MAX_JAIL_ATTEMPTS {
+                storage::set_jail_attempts(&env, game_id, &player, attempts + 1);
+                state.moved = true;
+                storage::set_turn_state(&env, game_id, &state);
+                Self::record_action(&env, game_id, &player);
+                return;
+            } else {
+                if !Self::pay_debt(&env, game_id, &player, None, board::JAIL_BAIL) {
+                    return;
+                }
+                Self::release_from_jail(&env, game_id, &player, JailRelease::Bail);
+            }
+        }
+
+        let from = storage::get_position(&env, game_id, &player);
+        let steps = from + dice_total;
+        let to = steps % board::BOARD_SIZE;
+        let passed_go = steps >= board::BOARD_SIZE;
+        if passed_go {
+            Self::credit_cash(&env, game_id, &player, board::GO_SALARY);
+        }
+
+        storage::set_position(&env, game_id, &player, to);
+        state.moved = true;
+        storage::set_turn_state(&env, game_id, &state);
+        Self::record_action(&env, game_id, &player);
+
+        events::emit_player_moved(
+            &env,
+            &events::PlayerMovedData {
+                game_id,
+                player: player.clone(),
+                from,
+                to,
+                passed_go,
+            },
+        );
+
+        Self::resolve_landing(&env, game_id, &player, to, dice_total);
+    }
+
+    /// Buy the property the current player's token landed on this turn, at
+    /// its list price, from in-game cash.
+    ///
+    /// Emits `PropertyPurchased`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Not your turn"` — `player` is not the current player.
+    /// - `"Player has not moved this turn"`
+    /// - `"Square is not a property"` — the square cannot be owned.
+    /// - `"Property already owned"`
+    /// - `"Property is being auctioned"`
+    /// - `"Insufficient cash"`
+    pub fn buy_property(env: Env, game_id: u64, player: Address) {
+        player.require_auth();
+
+        Self::load_ongoing_game(&env, game_id);
+        let state = Self::require_current_player(&env, game_id, &player);
+        if !state.moved {
+            panic!("Player has not moved this turn");
+        }
+
+        let square_index = storage::get_position(&env, game_id, &player);
+        let square = board::square(square_index);
+        if !square.is_ownable() {
+            panic!("Square is not a property");
+        }
+        if storage::get_property_owner(&env, game_id, square_index).is_some() {
+            panic!("Property already owned");
+        }
+        if storage::get_auction(&env, game_id, square_index).is_some() {
+            panic!("Property is being auctioned");
+        }
+
+        Self::debit_cash(&env, game_id, &player, square.price);
+        Self::grant_property(&env, game_id, &player, square_index);
+        Self::record_action(&env, game_id, &player);
+
+        events::emit_property_purchased(
+            &env,
+            &events::PropertyPurchasedData {
+                game_id,
+                player,
+                square: square_index,
+                price: square.price,
+            },
+        );
+    }
+
+    /// Decline to buy the unowned property the current player landed on,
+    /// putting it up for auction among the other players. Requires
+    /// `GameSettings.auction`.
+    ///
+    /// The auction accepts ascending bids for `AUCTION_DURATION` seconds.
+    ///
+    /// Emits `AuctionOpened`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Auction mode is disabled"`
+    /// - `"Not your turn"` — `player` is not the current player.
+    /// - `"Player has not moved this turn"`
+    /// - `"Square is not a property"` — the square cannot be owned.
+    /// - `"Property already owned"`
+    /// - `"Property is being auctioned"`
+    pub fn decline_property(env: Env, game_id: u64, player: Address) {
+        player.require_auth();
+
+        Self::load_ongoing_game(&env, game_id);
+        let settings = storage::get_game_settings(&env, game_id)
+            .unwrap_or_else(|| panic!("Game settings not found"));
+        if !settings.auction {
+            panic!("Auction mode is disabled");
+        }
+
+        let state = Self::require_current_player(&env, game_id, &player);
+        if !state.moved {
+            panic!("Player has not moved this turn");
+        }
+
+        let square = storage::get_position(&env, game_id, &player);
+        if !board::square(square).is_ownable() {
+            panic!("Square is not a property");
+        }
+        if storage::get_property_owner(&env, game_id, square).is_some() {
+            panic!("Property already owned");
+        }
+        if storage::get_auction(&env, game_id, square).is_some() {
+            panic!("Property is being auctioned");
+        }
+
+        Self::record_action(&env, game_id, &player);
+        Self::open_auction(&env, game_id, square, Some(player));
+    }
+
+    /// Bid in-game cash on an auctioned property. Callable by any player
+    /// still in the game other than the one who declined it.
+    ///
+    /// The bid must beat the current leading bid. It is escrowed from the
+    /// bidder's cash, and the previous leader is refunded.
+    ///
+    /// Emits `BidPlaced`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Auction not found"`
+    /// - `"Auction has ended"`
+    /// - `"Bidder is not in this game"`
+    /// - `"Bidder is excluded from this auction"`
+    /// - `"Bid too low"`
+    /// - `"Insufficient cash"`
+    pub fn place_bid(env: Env, game_id: u64, square: u32, bidder: Address, amount: u128) {
+        bidder.require_auth();
+
+        Self::load_ongoing_game(&env, game_id);
+        let mut auction = storage::get_auction(&env, game_id, square)
+            .unwrap_or_else(|| panic!("Auction not found"));
+        if env.ledger().timestamp() >= auction.ends_at {
+            panic!("Auction has ended");
+        }
+
+        let turn_order = storage::get_turn_order(&env, game_id).unwrap_or(Vec::new(&env));
+        if !turn_order.contains(&bidder) {
+            panic!("Bidder is not in this game");
+        }
+        if auction.excluded.as_ref() == Some(&bidder) {
+            panic!("Bidder is excluded from this auction");
+        }
+        if amount <= auction.highest_bid {
+            panic!("Bid too low");
+        }
+
+        if let Some(previous) = auction.highest_bidder.clone() {
+            Self::credit_cash(&env, game_id, &previous, auction.highest_bid);
+        }
+        Self::debit_cash(&env, game_id, &bidder, amount);
+
+        auction.highest_bidder = Some(bidder.clone());
+        auction.highest_bid = amount;
+        storage::set_auction(&env, game_id, &auction);
+        Self::record_action(&env, game_id, &bidder);
+
+        events::emit_bid_placed(
+            &env,
+            &events::BidPlacedData {
+                game_id,
+                square,
+                bidder,
+                amount,
+            },
+        );
+    }
+
+    /// Close an auction once bidding has ended, awarding the property to the
+    /// highest bidder. With no bids the property stays with the bank.
+    /// Callable by anyone.
+    ///
+    /// Emits `AuctionSettled`.
+    ///
+    /// # Panics
+    /// - `"Auction not found"`
+    /// - `"Auction is still open"`
+    pub fn settle_auction(env: Env, game_id: u64, square: u32) {
+        let auction = storage::get_auction(&env, game_id, square)
+            .unwrap_or_else(|| panic!("Auction not found"));
+        if env.ledger().timestamp() < auction.ends_at {
+            panic!("Auction is still open");
+        }
+
+        storage::remove_auction(&env, game_id, square);
+        if let Some(winner) = auction.highest_bidder.clone() {
+            Self::grant_property(&env, game_id, &winner, square);
+        }
+
+        events::emit_auction_settled(
+            &env,
+            &events::AuctionSettledData {
+                game_id,
+                square,
+                winner: auction.highest_bidder,
+                price: auction.highest_bid,
+            },
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Jail
+    // -----------------------------------------------------------------------
+
+    /// Pay `board::JAIL_BAIL` to leave jail before moving this turn.
+    ///
+    /// Emits `JailReleased`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Not your turn"` — `player` is not the current player.
+    /// - `"Player is not in jail"`
+    /// - `"Already moved this turn"`
+    /// - `"Insufficient cash"`
+    pub fn pay_bail(env: Env, game_id: u64, player: Address) {
+        player.require_auth();
+
+        Self::load_ongoing_game(&env, game_id);
+        Self::require_jailed_before_move(&env, game_id, &player);
+
+        Self::debit_cash(&env, game_id, &player, board::JAIL_BAIL);
+        Self::release_from_jail(&env, game_id, &player, JailRelease::Bail);
+    }
+
+    /// Leave jail before moving this turn by burning a tycoon-collectibles
+    /// JailFree perk owned by `player`.
+    ///
+    /// The collectible is burned through the collectibles contract's
+    /// `burn_collectible_for_perk`, so `player` must also authorize that
+    /// call.
+    ///
+    /// Emits `JailReleased`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Not your turn"` — `player` is not the current player.
+    /// - `"Player is not in jail"`
+    /// - `"Already moved this turn"`
+    /// - `"Collectibles contract not set"`
+    /// - `"Collectible is not a JailFree perk"`
+    /// - Any failure of the burn, e.g. the player does not hold the token.
+    pub fn use_jail_free_perk(env: Env, game_id: u64, player: Address, token_id: u128) {
+        player.require_auth();
+
+        Self::load_ongoing_game(&env, game_id);
+        Self::require_jailed_before_move(&env, game_id, &player);
+
+        let collectibles = storage::get_collectibles_contract(&env)
+            .unwrap_or_else(|| panic!("Collectibles contract not set"));
+        let perk: u32 = env.invoke_contract(
+            &collectibles,
+            &Symbol::new(&env, "get_token_perk"),
+            vec![&env, token_id.into_val(&env)],
+        );
+        if perk != JAIL_FREE_PERK {
+            panic!("Collectible is not a JailFree perk");
+        }
+        env.invoke_contract::<()>(
+            &collectibles,
+            &Symbol::new(&env, "burn_collectible_for_perk"),
+            vec![&env, player.into_val(&env), token_id.into_val(&env)],
+        );
+
+        Self::release_from_jail(&env, game_id, &player, JailRelease::Perk);
+    }
+
+    // -----------------------------------------------------------------------
+    // Development and mortgages
+    // -----------------------------------------------------------------------
+
+    /// Build a house on a color property, or a hotel on one with four
+    /// houses, paying `house_cost` to the bank.
+    ///
+    /// The owner must hold the whole color set with nothing in it mortgaged,
+    /// and must build evenly: a property may not get ahead of the least
+    /// developed one in its set.
+    ///
+    /// Emits `PropertyDeveloped`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Not the property owner"`
+    /// - `"Square cannot be developed"` — railroads, utilities and non-ownable squares.
+    /// - `"Color set not complete"`
+    /// - `"Color set has a mortgaged property"`
+    /// - `"Property fully developed"` — already has a hotel.
+    /// - `"Must build evenly across the color set"`
+    /// - `"Insufficient cash"`
+    pub fn build_house(env: Env, game_id: u64, player: Address, square: u32) {
+        player.require_auth();
+
+        Self::load_ongoing_game(&env, game_id);
+        let definition = Self::require_developable(&env, game_id, &player, square);
+
+        for index in board::group_squares(definition.group) {
+            if storage::get_property_owner(&env, game_id, index).as_ref() != Some(&player) {
+                panic!("Color set not complete");
+            }
+            if storage::is_mortgaged(&env, game_id, index) {
+                panic!("Color set has a mortgaged property");
+            }
+        }
+
+        let level = storage::get_development(&env, game_id, square);
+        if level >= board::HOTEL_LEVEL {
+            panic!("Property fully developed");
+        }
+        if board::group_squares(definition.group)
+            .any(|index| storage::get_development(&env, game_id, index) < level)
+        {
+            panic!("Must build evenly across the color set");
+        }
+
+        Self::debit_cash(&env, game_id, &player, definition.house_cost);
+        Self::set_level(&env, game_id, &player, square, level + 1);
+    }
+
+    /// Sell a house (or break a hotel back down to four houses) to the bank
+    /// for half its `house_cost`. Houses must be sold evenly: a property may
+    /// not fall behind the most developed one in its set.
+    ///
+    /// Emits `PropertyDeveloped`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Not the property owner"`
+    /// - `"Square cannot be developed"`
+    /// - `"No houses to sell"`
+    /// - `"Must sell evenly across the color set"`
+    pub fn sell_house(env: Env, game_id: u64, player: Address, square: u32) {
+        player.require_auth();
+
+        Self::load_ongoing_game(&env, game_id);
+        let definition = Self::require_developable(&env, game_id, &player, square);
+
+        let level = storage::get_development(&env, game_id, square);
+        if level == 0 {
+            panic!("No houses to sell");
+        }
+        if board::group_squares(definition.group)
+            .any(|index| storage::get_development(&env, game_id, index) > level)
+        {
+            panic!("Must sell evenly across the color set");
+        }
+
+        Self::credit_cash(&env, game_id, &player, definition.house_cost / 2);
+        Self::set_level(&env, game_id, &player, square, level - 1);
+    }
+
+    /// Mortgage an owned property for half its list price. Mortgaged
+    /// properties collect no rent.
+    ///
+    /// Emits `MortgageChanged`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Not the property owner"`
+    /// - `"Property already mortgaged"`
+    /// - `"Sell houses before mortgaging"` — any property in the color set is developed.
+    pub fn mortgage_property(env: Env, game_id: u64, player: Address, square: u32) {
+        player.require_auth();
+
+        Self::load_ongoing_game(&env, game_id);
+        Self::require_property_owner(&env, game_id, &player, square);
+        if storage::is_mortgaged(&env, game_id, square) {
+            panic!("Property already mortgaged");
+        }
+        let definition = board::square(square);
+        if board::group_squares(definition.group)
+            .any(|index| storage::get_development(&env, game_id, index) > 0)
+        {
+            panic!("Sell houses before mortgaging");
+        }
+
+        let amount = definition.price / 2;
+        storage::set_mortgaged(&env, game_id, square, true);
+        Self::credit_cash(&env, game_id, &player, amount);
+        Self::record_action(&env, game_id, &player);
+
+        events::emit_mortgage_changed(
+            &env,
+            &events::MortgageChangedData {
+                game_id,
+                owner: player,
+                square,
+                mortgaged: true,
+                amount,
+            },
+        );
+    }
+
+    /// Lift a mortgage by repaying the mortgage value plus
+    /// `MORTGAGE_INTEREST_BPS` interest.
+    ///
+    /// Emits `MortgageChanged`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Not the property owner"`
+    /// - `"Property is not mortgaged"`
+    /// - `"Insufficient cash"`
+    pub fn unmortgage_property(env: Env, game_id: u64, player: Address, square: u32) {
+        player.require_auth();
+
+        Self::load_ongoing_game(&env, game_id);
+        Self::require_property_owner(&env, game_id, &player, square);
+        if !storage::is_mortgaged(&env, game_id, square) {
+            panic!("Property is not mortgaged");
+        }
+
+        let principal = board::square(square).price / 2;
+        let amount = principal + principal * MORTGAGE_INTEREST_BPS / 10_000;
+        Self::debit_cash(&env, game_id, &player, amount);
+        storage::set_mortgaged(&env, game_id, square, false);
+        Self::record_action(&env, game_id, &player);
+
+        events::emit_mortgage_changed(
+            &env,
+            &events::MortgageChangedData {
+                game_id,
+                owner: player,
+                square,
+                mortgaged: false,
+                amount,
+            },
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Trades
+    // -----------------------------------------------------------------------
+
+    /// Offer `counterparty` a swap: the proposer hands over `give_squares`
+    /// and `give_cash` in exchange for `want_squares` and `want_cash`.
+    /// Returns the trade ID.
+    ///
+    /// The offer expires when the turn advances. Cash balances are checked
+    /// when the offer is accepted.
+    ///
+    /// Emits `TradeProposed`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Player is not in this game"` — either side is not in the turn order.
+    /// - `"Cannot trade with yourself"`
+    /// - `"Trade is empty"` — nothing changes hands.
+    /// - `"Property not owned by proposer"`
+    /// - `"Property not owned by counterparty"`
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_trade(
+        env: Env,
+        game_id: u64,
+        proposer: Address,
+        counterparty: Address,
+        give_squares: Vec<u32>,
+        give_cash: u128,
+        want_squares: Vec<u32>,
+        want_cash: u128,
+    ) -> u32 {
+        proposer.require_auth();
+
+        Self::load_ongoing_game(&env, game_id);
+        Self::open_trade(
+            &env,
+            game_id,
+            proposer,
+            counterparty,
+            give_squares,
+            give_cash,
+            want_squares,
+            want_cash,
+        )
+    }
+
+    /// Accept a trade offer, swapping the properties and cash atomically.
+    ///
+    /// Emits `TradeAccepted`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not ongoing"` — game is pending or already ended.
+    /// - `"Trade offer not found"`
+    /// - `"Not the trade counterparty"`
+    /// - `"Trade offer expired"` — the turn has advanced since the offer.
+    /// - `"Property not owned by proposer"`
+    /// - `"Property not owned by counterparty"`
+    /// - `"Insufficient cash"` — either side cannot cover its cash leg.
+    /// - `"Sell houses before trading"` — any property in a traded color set is developed.
+    pub fn accept_trade(env: Env, game_id: u64, trade_id: u32, counterparty: Address) {
+        counterparty.require_auth();
+
+        Self::load_ongoing_game(&env, game_id);
+        let offer = Self::load_trade_for_counterparty(&env, game_id, trade_id, &counterparty);
+        storage::remove_trade(&env, game_id, trade_id);
+
+        let proposer = offer.proposer;
+        for square in offer.give_squares.iter() {
+            Self::transfer_property(
+                &env,
+                game_id,
+                &proposer,
+                &counterparty,
+                square,
+                "Property not owned by proposer",
+            );
+        }
+        for square in offer.want_squares.iter() {
+            Self::transfer_property(
+                &env,
+                game_id,
+                &counterparty,
+                &proposer,
+                square,
+                "Property not owned by counterparty",
+            );
+        }
+        Self::debit_cash(&env, game_id, &proposer, offer.give_cash);
+        Self::credit_cash(&env, game_id, &counterparty, offer.give_cash);
+        Self::debit_cash(&env, game_id, &counterparty, offer.want_cash);
+        Self::credit_cash(&env, game_id, &proposer, offer.want_cash);
+
+        Self::record_action(&env, game_id, &counterparty);
+
+        events::emit_trade_accepted(&env, &events::TradeAcceptedData { game_id, trade_id });
+    }
+
+    /// Reject a trade offer as the counterparty, or withdraw it as the
+    /// proposer. Expired offers can still be cleared this way.
+    ///
+    /// Emits `TradeRejected`.
+    ///
+    /// # Panics
+    /// - `"Trade offer not found"`
+    /// - `"Caller is not a party to this trade"`
+    pub fn reject_trade(env: Env, game_id: u64, trade_id: u32, caller: Address) {
+        caller.require_auth();
+
+        let offer = storage::get_trade(&env, game_id, trade_id)
+            .unwrap_or_else(|| panic!("Trade offer not found"));
+        if caller != offer.proposer && caller != offer.counterparty {
+            panic!("Caller is not a party to this trade");
+        }
+        storage::remove_trade(&env, game_id, trade_id);
+
+        events::emit_trade_rejected(
+            &env,
+            &events::TradeRejectedData {
+                game_id,
+                trade_id,
+                rejected_by: caller,
+                counter_id: None,
+            },
+        );
+    }
+
+    /// Reject a trade offer and propose a different bundle back to the
+    /// original proposer. The bundle is from the counterparty's side:
+    /// `give_*` is what they hand over. Returns the new trade ID.
+    ///
+    /// Emits `TradeRejected` (with `counter_id`) and `TradeProposed`.
+    ///
+    /// # Panics
+    /// - Same as `accept_trade` for looking up the offer, plus the
+    ///   validation panics of `propose_trade` for the counter-offer.
+    #[allow(clippy::too_many_arguments)]
+    pub fn counter_trade(
+        env: Env,
+        game_id: u64,
+        trade_id: u32,
+        counterparty: Address,
+        give_squares: Vec<u32>,
+        give_cash: u128,
+        want_squares: Vec<u32>,
+        want_cash: u128,
+    ) -> u32 {
+        counterparty.require_auth();
+
+        Self::load_ongoing_game(&env, game_id);
+        let offer = Self::load_trade_for_counterparty(&env, game_id, trade_id, &counterparty);
+        storage::remove_trade(&env, game_id, trade_id);
+
+        let counter_id = Self::open_trade(
+            &env,
+            game_id,
+            counterparty.clone(),
+            offer.proposer,
+            give_squares,
+            give_cash,
+            want_squares,
+            want_cash,
+        );
+
+        events::emit_trade_rejected(
+            &env,
+            &events::TradeRejectedData {
+                game_id,
+                trade_id,
+                rejected_by: counterparty,
+                counter_id: Some(counter_id),
+            },
+        );
+        counter_id
+    }
+
+    // -----------------------------------------------------------------------
+    // View functions
+    // -----------------------------------------------------------------------
+
+    /// Returns the owner address stored during initialization.
+    pub fn get_owner(env: Env) -> Address {
+        storage::get_owner(&env)
+    }
+
+    /// Returns the reward system contract address stored during initialization.
+    pub fn get_reward_system(env: Env) -> Address {
+        storage::get_reward_system(&env)
+    }
+
+    /// Returns true if the given address has been registered as a player.
+    pub fn is_registered(env: Env, address: Address) -> bool {
+        storage::is_registered(&env, &address)
+    }
+
+    /// Returns the username of a registered player, if any.
+    pub fn get_username(env: Env, address: Address) -> Option<String> {
+        storage::get_username(&env, &address)
+    }
+
+    /// Returns the TYC value of the registration voucher.
+    pub fn get_registration_voucher_value(env: Env) -> u128 {
+        storage::get_registration_voucher_value(&env)
+    }
+
+    /// Retrieves a game by ID. Returns `None` if not found.
+    pub fn get_game(env: Env, game_id: u64) -> Option<Game> {
+        storage::get_game(&env, game_id)
+    }
+
+    /// Retrieves settings for a game by ID. Returns `None` if not found.
+    pub fn get_game_settings(env: Env, game_id: u64) -> Option<GameSettings> {
+        storage::get_game_settings(&env, game_id)
+    }
+
+    /// Returns the protocol fee in basis points.
+    pub fn get_protocol_fee(env: Env) -> u32 {
+        storage::get_protocol_fee_bps(&env)
+    }
+
+    /// Returns the accrued, withdrawable protocol fees for `token`.
+    pub fn get_treasury_balance(env: Env, token: Address) -> u128 {
+        storage::get_treasury_balance(&env, &token)
+    }
+
+    /// Returns the backend game controller address, if one has been set.
+    pub fn get_backend_game_controller(env: Env) -> Option<Address> {
+        storage::get_backend_game_controller(&env)
+    }
+
+    /// Retrieves the final ranked placements for a game. Returns `None` until
+    /// it is settled.
+    pub fn get_placements(env: Env, game_id: u64) -> Option<Vec<Address>> {
+        storage::get_placements(&env, game_id)
+    }
+
+    /// Retrieves the turn order of the players still in a game. Returns
+    /// `None` until it starts.
+    pub fn get_turn_order(env: Env, game_id: u64) -> Option<Vec<Address>> {
+        storage::get_turn_order(&env, game_id)
+    }
+
+    /// Retrieves the current turn state for a game. Returns `None` until it
+    /// starts.
+    pub fn get_turn_state(env: Env, game_id: u64) -> Option<TurnState> {
+        storage::get_turn_state(&env, game_id)
+    }
+
+    /// Returns the timestamp of `player`'s last action in a game, or `None` if
+    /// they have not acted since the game started.
+    pub fn get_last_action(env: Env, game_id: u64, player: Address) -> Option<u64> {
+        storage::get_last_action(&env, game_id, &player)
+    }
+
+    /// Returns the registered result signer public key, if any.
+    pub fn get_result_signer(env: Env) -> Option<BytesN<32>> {
+        storage::get_result_signer(&env)
+    }
+
+    /// Returns the last signed-result nonce accepted for a game.
+    pub fn get_result_nonce(env: Env, game_id: u64) -> u64 {
+        storage::get_result_nonce(&env, game_id)
+    }
+
+    /// Returns the final state hash attested for a game, if any.
+    pub fn get_result_state_hash(env: Env, game_id: u64) -> Option<BytesN<32>> {
+        storage::get_result_state_hash(&env, game_id)
+    }
+
+    /// Returns the dispute window in seconds and the dispute bond in USDC.
+    pub fn get_dispute_config(env: Env) -> (u64, u128) {
+        (
+            storage::get_dispute_window(&env),
+            storage::get_dispute_bond(&env),
+        )
+    }
+
+    /// Returns the dispute arbiter address, if set.
+    pub fn get_arbiter(env: Env) -> Option<Address> {
+        storage::get_arbiter(&env)
+    }
+
+    /// Returns the result awaiting finalization for a game, if any.
+    pub fn get_pending_result(env: Env, game_id: u64) -> Option<PendingResult> {
+        storage::get_pending_result(&env, game_id)
+    }
+
+    /// Returns the settlement timeout in seconds applied to newly started games.
+    pub fn get_settlement_timeout(env: Env) -> u64 {
+        storage::get_settlement_timeout(&env)
+    }
+
+    /// Returns the timestamp after which players can refund an unsettled
+    /// game. Returns `None` until it starts.
+    pub fn get_settlement_deadline(env: Env, game_id: u64) -> Option<u64> {
+        storage::get_settlement_deadline(&env, game_id)
+    }
+
+    /// Returns the dice round for a game turn, if one was started.
+    pub fn get_dice_round(env: Env, game_id: u64, turn_number: u32) -> Option<DiceRound> {
+        storage::get_dice_round(&env, game_id, turn_number)
+    }
+
+    /// Returns a player's in-game cash balance.
+    pub fn get_cash(env: Env, game_id: u64, player: Address) -> u128 {
+        storage::get_cash(&env, game_id, &player)
+    }
+
+    /// Returns the board square a player's token is on.
+    pub fn get_position(env: Env, game_id: u64, player: Address) -> u32 {
+        storage::get_position(&env, game_id, &player)
+    }
+
+    /// Returns the owner of a board square, if it has been bought.
+    pub fn get_property_owner(env: Env, game_id: u64, square: u32) -> Option<Address> {
+        storage::get_property_owner(&env, game_id, square)
+    }
+
+    /// Returns the squares a player owns, in purchase order.
+    pub fn get_properties(env: Env, game_id: u64, player: Address) -> Vec<u32> {
+        storage::get_player_properties(&env, game_id, &player)
+    }
+
+    /// Returns the open auction for a square, if any.
+    pub fn get_auction(env: Env, game_id: u64, square: u32) -> Option<Auction> {
+        storage::get_auction(&env, game_id, square)
+    }
+
+    /// Returns a jailed player's failed doubles attempts, or `None` when the
+    /// player is not in jail.
+    pub fn get_jail_attempts(env: Env, game_id: u64, player: Address) -> Option<u32> {
+        storage::get_jail_attempts(&env, game_id, &player)
+    }
+
+    /// Returns the tycoon-collectibles contract address, if configured.
+    pub fn get_collectibles_contract(env: Env) -> Option<Address> {
+        storage::get_collectibles_contract(&env)
+    }
+
+    /// Returns a property's development level: 0–4 houses, or 5 for a hotel.
+    pub fn get_development(env: Env, game_id: u64, square: u32) -> u32 {
+        storage::get_development(&env, game_id, square)
+    }
+
+    /// Returns whether a property is mortgaged.
+    pub fn is_mortgaged(env: Env, game_id: u64, square: u32) -> bool {
+        storage::is_mortgaged(&env, game_id, square)
+    }
+
+    /// Returns an open trade offer, if it has not been accepted, rejected or
+    /// countered. Expired offers are still returned until cleared.
+    pub fn get_trade(env: Env, game_id: u64, trade_id: u32) -> Option<TradeOffer> {
+        storage::get_trade(&env, game_id, trade_id)
+    }
+
+    /// Returns a player's net worth: cash plus the value of their
+    /// properties and houses (see `net_worth`).
+    pub fn get_net_worth(env: Env, game_id: u64, player: Address) -> u128 {
+        Self::net_worth(&env, game_id, &player)
+    }
+
+    /// Returns the standings derived from on-chain state: remaining players
+    /// by net worth (highest first, ties in turn order), then eliminated
+    /// players in reverse elimination order.
+    pub fn get_standings(env: Env, game_id: u64) -> Vec<Address> {
+        let remaining = storage::get_turn_order(&env, game_id).unwrap_or(Vec::new(&env));
+
+        let mut standings: Vec<Address> = Vec::new(&env);
+        let mut worths: Vec<u128> = Vec::new(&env);
+        for player in remaining.iter() {
+            let worth = Self::net_worth(&env, game_id, &player);
+            let mut index = worths.len();
+            while index > 0 && worths.get(index - 1).unwrap_or(0) < worth {
+                index -= 1;
+            }
+            standings.insert(index, player);
+            worths.insert(index, worth);
+        }
+
+        for out in storage::get_eliminated(&env, game_id).iter().rev() {
+            standings.push_back(out);
+        }
+        standings
+    }
+
+    /// Returns the players eliminated from a game, first out first.
+    pub fn get_eliminated(env: Env, game_id: u64) -> Vec<Address> {
+        storage::get_eliminated(&env, game_id)
+    }
+}
+
+impl TycoonMainGame {
+    /// Refunds `stake_per_player` in USDC from the contract to `player`, if
+    /// the game is staked.
+    fn refund_stake(env: &Env, game: &Game, player: &Address) {
+        if game.stake_per_player > 0 {
+            let usdc_token = storage::get_usdc_token(env);
+            let token_client = token::Client::new(env, &usdc_token);
+            let contract_address = env.current_contract_address();
+            token_client.transfer(&contract_address, player, &(game.stake_per_player as i128));
+        }
+    }
+
+    /// Refunds every joined player of a pending game and marks it `Ended`.
+    /// Returns the payload for the caller's closing event.
+    fn close_pending_game(env: &Env, game: &mut Game) -> events::PendingGameClosedData {
+        for player in game.joined_players.iter() {
+            Self::refund_stake(env, game, &player);
+        }
+
+        let total_refunded = game.total_staked;
+
+        game.status = GameStatus::Ended;
+        game.total_staked = 0;
+        game.ended_at = env.ledger().timestamp();
+
+        storage::set_game(env, game);
+
+        events::PendingGameClosedData {
+            game_id: game.id,
+            players_refunded: game.joined_players.len(),
+            total_refunded,
+        }
+    }
+
+    /// Checks that `placements` covers every paid place that the joined
+    /// players can fill and only ranks distinct players who joined `game`.
+    fn validate_placements(env: &Env, game: &Game, placements: &Vec<Address>) {
+        let settings = storage::get_game_settings(env, game.id)
+            .unwrap_or_else(|| panic!("Game settings not found"));
+
+        let required = payout::paid_places(&settings.payout_bps).min(game.joined_players.len());
+        if placements.len() < required {
+            panic!("Not enough placements for payout table");
+        }
+
+        let mut seen: Vec<Address> = Vec::new(env);
+        for player in placements.iter() {
+            if !game.joined_players.contains(&player) {
+                panic!("Placement is not in this game");
+            }
+            if seen.contains(&player) {
+                panic!("Duplicate placement");
+            }
+            seen.push_back(player);
+        }
+    }
+
+    /// Splits the pot of an ongoing game across `placements` according to
+    /// the game's payout table, marks it `Ended` with nothing left staked and
+    /// emits `GameSettled`.
+    fn settle_game(env: &Env, game: &mut Game, placements: &Vec<Address>) {
+        let settings = storage::get_game_settings(env, game.id)
+            .unwrap_or_else(|| panic!("Game settings not found"));
+
+        let usdc_token = storage::get_usdc_token(env);
+        let pot = game.total_staked;
+        let fee = payout::apply_bps(pot, storage::get_protocol_fee_bps(env));
+        let mut payouts = payout::split_pot(env, pot - fee, &settings.payout_bps);
+
+        // A game that started or ended (by elimination) with fewer players
+        // than paid places leaves places unfilled; their shares go to 1st.
+        if payouts.len() > placements.len() {
+            let mut unclaimed = 0u128;
+            while payouts.len() > placements.len() {
+                unclaimed += payouts.pop_back().unwrap_or(0);
+            }
+            payouts.set(0, payouts.get(0).unwrap_or(0) + unclaimed);
+        }
+
+        if fee > 0 {
+            let treasury = storage::get_treasury_balance(env, &usdc_token);
+            storage::set_treasury_balance(env, &usdc_token, treasury + fee);
+        }
+
+        let token_client = token::Client::new(env, &usdc_token);
+        let contract_address = env.current_contract_address();
+        for (player, amount) in placements.iter().zip(payouts.iter()) {
+            if amount > 0 {
+                token_client.transfer(&contract_address, &player, &(amount as i128));
+            }
+        }
+
+        let winner = placements.get(0).unwrap_or_else(|| panic!("No winner"));
+
+        game.status = GameStatus::Ended;
+        game.total_staked = 0;
+        game.winner = Some(winner.clone());
+        game.ended_at = env.ledger().timestamp();
+
+        storage::set_game(env, game);
+        storage::set_placements(env, game.id, placements);
+
+        events::emit_game_settled(
+            env,
+            &events::GameSettledData {
+                game_id: game.id,
+                winner,
+                pot,
+                fee,
+                placements: placements.clone(),
+                payouts,
+            },
+        );
+    }
+
+    /// Moves a pending game to `Ongoing`: shuffles the joined players into
+    /// the turn order, stamps `started_at` and emits `GameStarted`.
+    fn begin_game(env: &Env, game: &mut Game) {
+        let mut turn_order = game.joined_players.clone();
+        env.prng().shuffle(&mut turn_order);
+
+        game.status = GameStatus::Ongoing;
+        game.started_at = env.ledger().timestamp();
+
+        storage::set_game(env, game);
+        storage::set_turn_order(env, game.id, &turn_order);
+        storage::set_settlement_deadline(
+            env,
+            game.id,
+            game.started_at
+                .saturating_add(storage::get_settlement_timeout(env)),
+        );
+        let settings = storage::get_game_settings(env, game.id)
+            .unwrap_or_else(|| panic!("Game settings not found"));
+        for player in turn_order.iter() {
+            storage::set_last_action(env, game.id, &player, game.started_at);
+            storage::set_cash(env, game.id, &player, settings.starting_cash);
+        }
+
+        events::emit_game_started(
+            env,
+            &events::GameStartedData {
+                game_id: game.id,
+                turn_order: turn_order.clone(),
+                started_at: game.started_at,
+            },
+        );
+
+        let mut state = TurnState {
+            current: 0,
+            turn_number: 0,
+            turn_started_at: game.started_at,
+            moved: false,
+        };
+        Self::start_turn(env, game, &turn_order, &mut state, 0);
+    }
+
+    /// Settles a validated result immediately, or holds it as a
+    /// `PendingResult` for the configured dispute window.
+    fn report_result(env: &Env, game: &mut Game, placements: &Vec<Address>) {
+        let window = storage::get_dispute_window(env);
+        if window == 0 {
+            Self::settle_game(env, game, placements);
+            return;
+        }
+
+        let reported_at = env.ledger().timestamp();
+        let pending = PendingResult {
+            placements: placements.clone(),
+            reported_at,
+            finalizes_at: reported_at.saturating_add(window),
+            bond: storage::get_dispute_bond(env),
+            disputed_by: None,
+        };
+
+        game.status = GameStatus::Reported;
+        storage::set_game(env, game);
+        storage::set_pending_result(env, game.id, &pending);
+
+        events::emit_result_reported(
+            env,
+            &events::ResultReportedData {
+                game_id: game.id,
+                placements: placements.clone(),
+                finalizes_at: pending.finalizes_at,
+            },
+        );
+    }
+
+    /// Loads a `Reported` game together with its pending result.
+    fn load_reported_game(env: &Env, game_id: u64) -> (Game, PendingResult) {
+        let game = storage::get_game(env, game_id).unwrap_or_else(|| panic!("Game not found"));
+        if !matches!(game.status, GameStatus::Reported) {
+            panic!("Game result is not reported");
+        }
+        let pending = storage::get_pending_result(env, game_id)
+            .unwrap_or_else(|| panic!("Game result is not reported"));
+        (game, pending)
+    }
+
+    /// Returns whether `caller` takes the player side of the current dice
+    /// round (`true`) or the backend side (`false`), panicking if neither.
+    fn is_dice_player(env: &Env, game_id: u64, state: &TurnState, caller: &Address) -> bool {
+        let turn_order = storage::get_turn_order(env, game_id).unwrap_or(Vec::new(env));
+        if turn_order.get(state.current).as_ref() == Some(caller) {
+            return true;
+        }
+        if storage::get_backend_game_controller(env).as_ref() == Some(caller) {
+            return false;
+        }
+        panic!("Unauthorized: caller must be current player or backend game controller");
+    }
+
+    /// Stores the outcome on a dice round and emits `DiceRolled`.
+    /// Uses up the current player's move after they withheld their dice
+    /// reveal. In jail this counts as a failed doubles attempt, so bail is
+    /// still forced after `board::MAX_JAIL_ATTEMPTS`.
+    fn forfeit_roll(env: &Env, game_id: u64, mut state: TurnState, round: &mut DiceRound) {
+        let turn_order = storage::get_turn_order(env, game_id).unwrap_or(Vec::new(env));
+        let player = turn_order
+            .get(state.current)
+            .unwrap_or_else(|| panic!("Current player not found"));
+
+        round.fallback = true;
+        round.forfeited = true;
+        storage::set_dice_round(env, game_id, state.turn_number, round);
+
+        events::emit_dice_forfeited(
+            env,
+            &events::DiceForfeitedData {
+                game_id,
+                turn_number: state.turn_number,
+                player: player.clone(),
+            },
+        );
+
+        if let Some(attempts) = storage::get_jail_attempts(env, game_id, &player) {
+            if attempts + 1 < board::MAX_JAIL_ATTEMPTS {
+                storage::set_jail_attempts(env, game_id, &player, attempts + 1);
+            } else {
+                if !Self::pay_debt(env, game_id, &player, None, board::JAIL_BAIL) {
+                    return;
+                }
+                Self::release_from_jail(env, game_id, &player, JailRelease::Bail);
+            }
+        }
+
+        state.moved = true;
+        storage::set_turn_state(env, game_id, &state);
+    }
+
+    fn record_roll(
+        env: &Env,
+        game_id: u64,
+        state: &TurnState,
+        round: &mut DiceRound,
+        die1: u32,
+        die2: u32,
+        fallback: bool,
+    ) {
+        round.die1 = die1;
+        round.die2 = die2;
+        round.fallback = fallback;
+        storage::set_dice_round(env, game_id, state.turn_number, round);
+
+        let turn_order = storage::get_turn_order(env, game_id).unwrap_or(Vec::new(env));
+        events::emit_dice_rolled(
+            env,
+            &events::DiceRolledData {
+                game_id,
+                turn_number: state.turn_number,
+                player: turn_order
+                    .get(state.current)
+                    .unwrap_or_else(|| panic!("Current player not found")),
+                die1,
+                die2,
+                fallback,
+            },
+        );
+    }
+
+    /// Adds `amount` to a player's in-game cash.
+    fn credit_cash(env: &Env, game_id: u64, player: &Address, amount: u128) {
+        let cash = storage::get_cash(env, game_id, player);
+        storage::set_cash(env, game_id, player, cash + amount);
+    }
+
+    /// Removes `amount` from a player's in-game cash.
+    fn debit_cash(env: &Env, game_id: u64, player: &Address, amount: u128) {
+        let cash = storage::get_cash(env, game_id, player);
+        if cash < amount {
+            panic!("Insufficient cash");
+        }
+        storage::set_cash(env, game_id, player, cash - amount);
+    }
+
+    /// Records `player` as the owner of `square`.
+    fn grant_property(env: &Env, game_id: u64, player: &Address, square: u32) {
+        storage::set_property_owner(env, game_id, square, player);
+        let mut owned = storage::get_player_properties(env, game_id, player);
+        owned.push_back(square);
+        storage::set_player_properties(env, game_id, player, &owned);
+    }
+
+    /// Moves `square` from `from` to `to` in the property ledger, panicking
+    /// with `not_owned` if `from` does not own it. Mortgages move with the
+    /// property; nothing in a developed color set can move.
+    fn transfer_property(
+        env: &Env,
+        game_id: u64,
+        from: &Address,
+        to: &Address,
+        square: u32,
+        not_owned: &str,
+    ) {
+        let mut owned = storage::get_player_properties(env, game_id, from);
+        let index = owned
+            .first_index_of(square)
+            .unwrap_or_else(|| panic!("{}", not_owned));
+        if board::group_squares(board::square(square).group)
+            .any(|index| storage::get_development(env, game_id, index) > 0)
+        {
+            panic!("Sell houses before trading");
+        }
+        owned.remove(index);
+        storage::set_player_properties(env, game_id, from, &owned);
+        Self::grant_property(env, game_id, to, square);
+    }
+
+    /// Validates and stores a new trade offer for the current turn, and
+    /// emits `TradeProposed`. Returns the trade ID.
+    #[allow(clippy::too_many_arguments)]
+    fn open_trade(
+        env: &Env,
+        game_id: u64,
+        proposer: Address,
+        counterparty: Address,
+        give_squares: Vec<u32>,
+        give_cash: u128,
+        want_squares: Vec<u32>,
+        want_cash: u128,
+    ) -> u32 {
+        let turn_order = storage::get_turn_order(env, game_id).unwrap_or(Vec::new(env));
+        if !turn_order.contains(&proposer) || !turn_order.contains(&counterparty) {
+            panic!("Player is not in this game");
+        }
+        if proposer == counterparty {
+            panic!("Cannot trade with yourself");
+        }
+        if give_squares.is_empty() && want_squares.is_empty() && give_cash == 0 && want_cash == 0 {
+            panic!("Trade is empty");
+        }
+
+        let proposer_owns = storage::get_player_properties(env, game_id, &proposer);
+        if give_squares.iter().any(|sq| !proposer_owns.contains(sq)) {
+            panic!("Property not owned by proposer");
+        }
+        let counterparty_owns = storage::get_player_properties(env, game_id, &counterparty);
+        if want_squares
+            .iter()
+            .any(|sq| !counterparty_owns.contains(sq))
+        {
+            panic!("Property not owned by counterparty");
+        }
+
+        let state =
+            storage::get_turn_state(env, game_id).unwrap_or_else(|| panic!("Turn state not found"));
+        let offer = TradeOffer {
+            id: storage::next_trade_id(env, game_id),
+            proposer,
+            counterparty,
+            give_squares,
+            give_cash,
+            want_squares,
+            want_cash,
+            turn_number: state.turn_number,
+        };
+        storage::set_trade(env, game_id, &offer);
+        Self::record_action(env, game_id, &offer.proposer);
+
+        events::emit_trade_proposed(
+            env,
+            &events::TradeProposedData {
+                game_id,
+                trade_id: offer.id,
+                proposer: offer.proposer.clone(),
+                counterparty: offer.counterparty.clone(),
+                give_squares: offer.give_squares.clone(),
+                give_cash: offer.give_cash,
+                want_squares: offer.want_squares.clone(),
+                want_cash: offer.want_cash,
+            },
+        );
+        offer.id
+    }
+
+    /// Loads a trade offer that `counterparty` may act on in the current turn.
+    fn load_trade_for_counterparty(
+        env: &Env,
+        game_id: u64,
+        trade_id: u32,
+        counterparty: &Address,
+    ) -> TradeOffer {
+        let offer = storage::get_trade(env, game_id, trade_id)
+            .unwrap_or_else(|| panic!("Trade offer not found"));
+        if offer.counterparty != *counterparty {
+            panic!("Not the trade counterparty");
+        }
+        let state =
+            storage::get_turn_state(env, game_id).unwrap_or_else(|| panic!("Turn state not found"));
+        if state.turn_number != offer.turn_number {
+            panic!("Trade offer expired");
+        }
+        offer
+    }
+
+    /// Opens an auction for an unowned `square`, barring `excluded` from
+    /// bidding, and emits `AuctionOpened`.
+    fn open_auction(env: &Env, game_id: u64, square: u32, excluded: Option<Address>) {
+        let auction = Auction {
+            square,
+            excluded,
+            highest_bidder: None,
+            highest_bid: 0,
+            ends_at: env.ledger().timestamp().saturating_add(AUCTION_DURATION),
+        };
+        storage::set_auction(env, game_id, &auction);
+
+        events::emit_auction_opened(
+            env,
+            &events::AuctionOpenedData {
+                game_id,
+                square,
+                ends_at: auction.ends_at,
+            },
+        );
+    }
+
+    /// Applies the effect of landing on `square`: rent to its owner or tax to
+    /// the bank.
+    fn resolve_landing(env: &Env, game_id: u64, player: &Address, square: u32, dice_total: u32) {
+        let definition = board::square(square);
+
+        if matches!(definition.kind, board::SquareKind::GoToJail) {
+            storage::set_position(env, game_id, player, board::JAIL_SQUARE);
+            storage::set_jail_attempts(env, game_id, player, 0);
+            events::emit_player_jailed(
+                env,
+                &events::PlayerJailedData {
+                    game_id,
+                    player: player.clone(),
+                },
+            );
+            return;
+        }
+
+        if matches!(definition.kind, board::SquareKind::Tax) {
+            if !Self::pay_debt(env, game_id, player, None, definition.price) {
+                return;
+            }
+            events::emit_tax_paid(
+                env,
+                &events::TaxPaidData {
+                    game_id,
+                    player: player.clone(),
+                    square,
+                    amount: definition.price,
+                },
+            );
+            return;
+        }
+
+        let Some(owner) = storage::get_property_owner(env, game_id, square) else {
+            return;
+        };
+        if owner == *player || storage::is_mortgaged(env, game_id, square) {
+            return;
+        }
+
+        let rent = Self::rent_due(env, game_id, square, &owner, dice_total);
+        if !Self::pay_debt(env, game_id, player, Some(&owner), rent) {
+            return;
+        }
+
+        events::emit_rent_paid(
+            env,
+            &events::RentPaidData {
+                game_id,
+                payer: player.clone(),
+                owner,
+                square,
+                amount: rent,
+            },
+        );
+    }
+
+    /// Panics unless `player` is the current player, in jail, and has not
+    /// moved yet this turn.
+    fn require_jailed_before_move(env: &Env, game_id: u64, player: &Address) {
+        let state = Self::require_current_player(env, game_id, player);
+        if storage::get_jail_attempts(env, game_id, player).is_none() {
+            panic!("Player is not in jail");
+        }
+        if state.moved {
+            panic!("Already moved this turn");
+        }
+    }
+
+    /// Takes `player` out of jail and emits `JailReleased`.
+    fn release_from_jail(env: &Env, game_id: u64, player: &Address, release: JailRelease) {
+        storage::remove_jail(env, game_id, player);
+        Self::record_action(env, game_id, player);
+
+        events::emit_jail_released(
+            env,
+            &events::JailReleasedData {
+                game_id,
+                player: player.clone(),
+                release,
+            },
+        );
+    }
+
+    /// Pays `amount` from `debtor` to `creditor`, or to the bank when
+    /// `creditor` is `None`.
+    ///
+    /// A debtor short of cash first sells houses and then mortgages
+    /// properties (see `raise_cash`). If that still isn't enough they go
+    /// bankrupt and this returns `false`.
+    fn pay_debt(
+        env: &Env,
+        game_id: u64,
+        debtor: &Address,
+        creditor: Option<&Address>,
+        amount: u128,
+    ) -> bool {
+        Self::raise_cash(env, game_id, debtor, amount);
+        if storage::get_cash(env, game_id, debtor) < amount {
+            Self::bankrupt_player(env, game_id, debtor, creditor, amount);
+            return false;
+        }
+
+        Self::debit_cash(env, game_id, debtor, amount);
+        if let Some(creditor) = creditor {
+            Self::credit_cash(env, game_id, creditor, amount);
+        }
+        true
+    }
+
+    /// Liquidates `player`'s assets until they hold at least `amount` cash:
+    /// first sells every house in a color set at a time, then mortgages
+    /// properties in purchase order.
+    fn raise_cash(env: &Env, game_id: u64, player: &Address, amount: u128) {
+        let owned = storage::get_player_properties(env, game_id, player);
+
+        for square in owned.iter() {
+            if storage::get_cash(env, game_id, player) >= amount {
+                return;
+            }
+            let definition = board::square(square);
+            for index in board::group_squares(definition.group) {
+                let level = storage::get_development(env, game_id, index);
+                if level > 0 {
+                    Self::credit_cash(
+                        env,
+                        game_id,
+                        player,
+                        definition.house_cost / 2 * level as u128,
+                    );
+                    Self::set_level(env, game_id, player, index, 0);
+                }
+            }
+        }
+
+        for square in owned.iter() {
+            if storage::get_cash(env, game_id, player) >= amount {
+                return;
+            }
+            if storage::is_mortgaged(env, game_id, square) {
+                continue;
+            }
+            let value = board::square(square).price / 2;
+            storage::set_mortgaged(env, game_id, square, true);
+            Self::credit_cash(env, game_id, player, value);
+
+            events::emit_mortgage_changed(
+                env,
+                &events::MortgageChangedData {
+                    game_id,
+                    owner: player.clone(),
+                    square,
+                    mortgaged: true,
+                    amount: value,
+                },
+            );
+        }
+    }
+
+    /// Declares `player` bankrupt over a debt of `debt`.
+    ///
+    /// Their remaining cash and (mortgaged) properties go to `creditor`.
+    /// When the bank is the creditor, cash is forfeited and the properties
+    /// return to the bank with the rest of the player's assets on
+    /// elimination, each put up for auction if the game continues. The
+    /// player is then eliminated, which ends the game when one player
+    /// remains.
+    fn bankrupt_player(
+        env: &Env,
+        game_id: u64,
+        player: &Address,
+        creditor: Option<&Address>,
+        debt: u128,
+    ) {
+        let cash = storage::get_cash(env, game_id, player);
+        let properties = storage::get_player_properties(env, game_id, player);
+
+        if let Some(creditor) = creditor {
+            storage::set_cash(env, game_id, player, 0);
+            Self::credit_cash(env, game_id, creditor, cash);
+            for square in properties.iter() {
+                Self::transfer_property(
+                    env,
+                    game_id,
+                    player,
+                    creditor,
+                    square,
+                    "Property not owned by debtor",
+                );
+            }
+        }
+
+        events::emit_player_bankrupt(
+            env,
+            &events::PlayerBankruptData {
+                game_id,
+                player: player.clone(),
+                creditor: creditor.cloned(),
+                debt,
+                cash,
+                properties: properties.clone(),
+            },
+        );
+
+        let mut game = storage::get_game(env, game_id).unwrap_or_else(|| panic!("Game not found"));
+        Self::eliminate_player(env, &mut game, player);
+
+        if creditor.is_none() && game.status == GameStatus::Ongoing {
+            for square in properties.iter() {
+                Self::open_auction(env, game_id, square, None);
+            }
+        }
+    }
+
+    /// Rent owed to `owner` for landing on `square`.
+    ///
+    /// - Property: base rent, doubled when `owner` holds the whole color set;
+    ///   developed properties use `board::developed_rent` instead.
+    /// - Railroad: 25, doubling for each additional railroad owned.
+    /// - Utility: 4x the dice total, or 10x with both utilities.
+    fn rent_due(env: &Env, game_id: u64, square: u32, owner: &Address, dice_total: u32) -> u128 {
+        let definition = board::square(square);
+
+        let mut owned_in_group = 0u32;
+        let mut group_size = 0u32;
+        for index in board::group_squares(definition.group) {
+            group_size += 1;
+            if storage::get_property_owner(env, game_id, index).as_ref() == Some(owner) {
+                owned_in_group += 1;
+            }
+        }
+
+        match definition.kind {
+            board::SquareKind::Property => {
+                let level = storage::get_development(env, game_id, square);
+                if level > 0 {
+                    board::developed_rent(definition.rent, level)
+                } else if owned_in_group == group_size {
+                    definition.rent * 2
+                } else {
+                    definition.rent
+                }
+            }
+            board::SquareKind::Railroad => definition.rent << (owned_in_group - 1),
+            board::SquareKind::Utility => {
+                let multiplier = if owned_in_group == group_size { 10 } else { 4 };
+                dice_total as u128 * multiplier
+            }
+            _ => 0,
+        }
+    }
+
+    /// Cash plus the list price of every property `player` owns and the
+    /// build cost of its houses. Mortgaged properties count for the list
+    /// price less the mortgage value.
+    fn net_worth(env: &Env, game_id: u64, player: &Address) -> u128 {
+        let mut worth = storage::get_cash(env, game_id, player);
+        for square in storage::get_player_properties(env, game_id, player).iter() {
+            let definition = board::square(square);
+            worth += if storage::is_mortgaged(env, game_id, square) {
+                definition.price - definition.price / 2
+            } else {
+                definition.price
+            };
+            worth += definition.house_cost * storage::get_development(env, game_id, square) as u128;
+        }
+        worth
+    }
+
+    /// Panics unless `player` owns `square`.
+    fn require_property_owner(env: &Env, game_id: u64, player: &Address, square: u32) {
+        if storage::get_property_owner(env, game_id, square).as_ref() != Some(player) {
+            panic!("Not the property owner");
+        }
+    }
+
+    /// Checks `player` owns color property `square` and returns its definition.
+    fn require_developable(
+        env: &Env,
+        game_id: u64,
+        player: &Address,
+        square: u32,
+    ) -> board::Square {
+        Self::require_property_owner(env, game_id, player, square);
+        let definition = board::square(square);
+        if !matches!(definition.kind, board::SquareKind::Property) {
+            panic!("Square cannot be developed");
+        }
+        definition
+    }
+
+    /// Stores a new development level and emits `PropertyDeveloped`.
+    fn set_level(env: &Env, game_id: u64, owner: &Address, square: u32, level: u32) {
+        storage::set_development(env, game_id, square, level);
+        Self::record_action(env, game_id, owner);
+
+        events::emit_property_developed(
+            env,
+            &events::PropertyDevelopedData {
+                game_id,
+                owner: owner.clone(),
+                square,
+                level,
+            },
+        );
+    }
+
+    /// Loads a game, panicking unless it is `Ongoing`.
+    fn load_ongoing_game(env: &Env, game_id: u64) -> Game {
+        let game = storage::get_game(env, game_id).unwrap_or_else(|| panic!("Game not found"));
+        if !matches!(game.status, GameStatus::Ongoing) {
+            panic!("Game is not ongoing");
+        }
+        game
+    }
+
+    /// Returns the turn state, panicking unless `player` holds the current
+    /// turn.
+    fn require_current_player(env: &Env, game_id: u64, player: &Address) -> TurnState {
+        let state =
+            storage::get_turn_state(env, game_id).unwrap_or_else(|| panic!("Turn state not found"));
+        let turn_order = storage::get_turn_order(env, game_id).unwrap_or(Vec::new(env));
+        if turn_order.get(state.current).as_ref() != Some(player) {
+            panic!("Not your turn");
+        }
+        state
+    }
+
+    /// Stamps `player`'s last action in a game with the current timestamp.
+    fn record_action(env: &Env, game_id: u64, player: &Address) {
+        storage::set_last_action(env, game_id, player, env.ledger().timestamp());
+    }
+
+    /// Hands the turn to `turn_order[index]`, bumping the turn number, and
+    /// emits `TurnStarted` with the turn deadline.
+    fn start_turn(
+        env: &Env,
+        game: &Game,
+        turn_order: &Vec<Address>,
+        state: &mut TurnState,
+        index: u32,
+    ) {
+        let settings = storage::get_game_settings(env, game.id)
+            .unwrap_or_else(|| panic!("Game settings not found"));
+
+        state.current = index;
+        state.turn_number += 1;
+        state.turn_started_at = env.ledger().timestamp();
+        state.moved = false;
+        storage::set_turn_state(env, game.id, state);
+
+        let deadline = if settings.turn_timeout == 0 {
+            0
+        } else {
+            state.turn_started_at.saturating_add(settings.turn_timeout)
+        };
+
+        events::emit_turn_started(
+            env,
+            &events::TurnStartedData {
+                game_id: game.id,
+                player: turn_order
+                    .get(index)
+                    .unwrap_or_else(|| panic!("Current player not found")),
+                turn_number: state.turn_number,
+                deadline,
+            },
+        );
+    }
+
+    /// Returns an eliminated player's properties to the bank, clearing
+    /// houses and mortgages, forfeits their cash, and voids their leading
+    /// bids so the auctions reopen from zero. Their bids were already taken
+    /// from their cash, so nothing is refunded.
+    fn return_assets_to_bank(env: &Env, game_id: u64, player: &Address) {
+        for square in storage::get_player_properties(env, game_id, player).iter() {
+            storage::remove_property_owner(env, game_id, square);
+            storage::set_mortgaged(env, game_id, square, false);
+            storage::set_development(env, game_id, square, 0);
+        }
+        storage::set_player_properties(env, game_id, player, &Vec::new(env));
+        storage::set_cash(env, game_id, player, 0);
+
+        for square in 0..board::BOARD_SIZE {
+            let Some(mut auction) = storage::get_auction(env, game_id, square) else {
+                continue;
+            };
+            if auction.highest_bidder.as_ref() == Some(player) {
+                auction.highest_bidder = None;
+                auction.highest_bid = 0;
+                storage::set_auction(env, game_id, &auction);
+            }
+        }
+    }
+
+    /// Removes `player` from an ongoing game's turn order and records them as
+    /// eliminated. Their stake stays in the pot. Their in-game assets go back
+    /// to the bank (see `return_assets_to_bank`).
+    ///
+    /// If one player remains the game is settled with the survivor first and
+    /// the eliminated players in reverse elimination order. Otherwise, if the
+    /// eliminated player held the turn, the next player's turn starts.
+    fn eliminate_player(env: &Env, game: &mut Game, player: &Address) {
+        Self::return_assets_to_bank(env, game.id, player);
+
+        let mut turn_order = storage::get_turn_order(env, game.id).unwrap_or(Vec::new(env));
+        let mut state =
+            storage::get_turn_state(env, game.id).unwrap_or_else(|| panic!("Turn state not found"));
+        let index = turn_order
+            .first_index_of(player)
+            .unwrap_or_else(|| panic!("Player is not in this game"));
+
+        turn_order.remove(index);
+        storage::set_turn_order(env, game.id, &turn_order);
+
+        let mut eliminated = storage::get_eliminated(env, game.id);
+        eliminated.push_back(player.clone());
+        storage::set_eliminated(env, game.id, &eliminated);
+
+        if turn_order.len() == 1 {
+            let mut placements = turn_order.clone();
+            for out in eliminated.iter().rev() {
+                placements.push_back(out);
+            }
+            Self::settle_game(env, game, &placements);
+            return;
+        }
+
+        if index < state.current {
+            state.current -= 1;
+            storage::set_turn_state(env, game.id, &state);
+        } else if index == state.current {
+            let next = index % turn_order.len();
+            Self::start_turn(env, game, &turn_order, &mut state, next);
+        }
     }
 }
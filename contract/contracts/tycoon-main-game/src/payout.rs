@@ -0,0 +1,75 @@
+use soroban_sdk::{Env, Vec};
+
+/// Denominator for basis-point values (100% = 10_000 bps).
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Validates a payout table for a lobby of `max_players`.
+///
+/// An empty table means winner-takes-all. A non-empty table lists the share
+/// of the pot, in basis points, paid to each place (1st, 2nd, ...).
+///
+/// # Panics
+/// - `"Payout table has more places than players"`
+/// - `"Payout share must be non-zero"`
+/// - `"Payout table must sum to 10000 bps"`
+pub fn validate_payout_table(table: &Vec<u32>, max_players: u32) {
+    if table.is_empty() {
+        return;
+    }
+
+    if table.len() > max_players {
+        panic!("Payout table has more places than players");
+    }
+
+    let mut total: u32 = 0;
+    for share in table.iter() {
+        if share == 0 {
+            panic!("Payout share must be non-zero");
+        }
+        total = total.saturating_add(share);
+    }
+
+    if total != BPS_DENOMINATOR {
+        panic!("Payout table must sum to 10000 bps");
+    }
+}
+
+/// Returns the number of paid places for a payout table.
+pub fn paid_places(table: &Vec<u32>) -> u32 {
+    if table.is_empty() {
+        1
+    } else {
+        table.len()
+    }
+}
+
+/// Returns `amount * bps / 10_000`, rounded down, without overflowing.
+pub fn apply_bps(amount: u128, bps: u32) -> u128 {
+    let denominator = BPS_DENOMINATOR as u128;
+    let bps = bps as u128;
+    (amount / denominator) * bps + (amount % denominator) * bps / denominator
+}
+
+/// Splits `pot` across places according to `table`.
+///
+/// Each place receives its share rounded down; the rounding remainder is
+/// added to 1st place so the amounts always sum to exactly `pot`.
+pub fn split_pot(env: &Env, pot: u128, table: &Vec<u32>) -> Vec<u128> {
+    let mut amounts = Vec::new(env);
+
+    if table.is_empty() {
+        amounts.push_back(pot);
+        return amounts;
+    }
+
+    let mut distributed: u128 = 0;
+    for share in table.iter() {
+        let amount = apply_bps(pot, share);
+        distributed += amount;
+        amounts.push_back(amount);
+    }
+
+    let first = amounts.get(0).unwrap_or(0);
+    amounts.set(0, first + (pot - distributed));
+    amounts
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
 
 // -----------------------------------------------------------------------
 // DataKey
@@ -24,6 +24,72 @@ pub enum DataKey {
     GameSettings(u64),
     /// Auto-incrementing game ID counter.
     NextGameId,
+    /// Backend game controller address allowed to drive game lifecycle.
+    BackendGameController,
+    /// Maps game_id -> turn order (Vec<Address>) fixed when the game starts.
+    /// Eliminated players are removed from it.
+    TurnOrder(u64),
+    /// Maps game_id -> final ranked placements (Vec<Address>, 1st first).
+    Placements(u64),
+    /// Protocol fee taken from each settled pot, in basis points.
+    ProtocolFeeBps,
+    /// Maps token address -> accrued protocol fees held for the treasury.
+    TreasuryBalance(Address),
+    /// Maps player address -> username chosen at registration.
+    Username(Address),
+    /// TYC value of the welcome voucher minted on registration.
+    RegistrationVoucherValue,
+    /// Maps game_id -> TurnState for an ongoing game.
+    TurnState(u64),
+    /// Maps (game_id, player) -> ledger timestamp of the player's last action.
+    LastAction(u64, Address),
+    /// Maps game_id -> eliminated players (Vec<Address>, first out first).
+    Eliminated(u64),
+    /// Seconds after `started_at` before players can refund an unsettled game.
+    SettlementTimeout,
+    /// Maps game_id -> timestamp after which players can refund the game,
+    /// fixed when the game starts.
+    SettlementDeadline(u64),
+    /// Ed25519 public key whose signed results can settle games.
+    ResultSigner,
+    /// Maps game_id -> last accepted signed-result nonce.
+    ResultNonce(u64),
+    /// Maps game_id -> final state hash from the accepted signed result.
+    ResultStateHash(u64),
+    /// Seconds a reported result stays open to disputes. Zero settles
+    /// results immediately.
+    DisputeWindow,
+    /// USDC bond a participant posts to dispute a reported result.
+    DisputeBond,
+    /// Address allowed, alongside the owner, to resolve disputes.
+    Arbiter,
+    /// Maps game_id -> PendingResult awaiting finalization.
+    PendingResult(u64),
+    /// Maps (game_id, turn_number) -> DiceRound for that turn.
+    DiceRound(u64, u32),
+    /// Maps (game_id, player) -> in-game cash balance.
+    Cash(u64, Address),
+    /// Maps (game_id, square) -> owner of a board property.
+    PropertyOwner(u64, u32),
+    /// Maps (game_id, player) -> squares owned (Vec<u32>, purchase order).
+    PlayerProperties(u64, Address),
+    /// Maps (game_id, player) -> board square the player's token is on.
+    Position(u64, Address),
+    /// Maps (game_id, square) -> open Auction for an unowned property.
+    Auction(u64, u32),
+    /// Maps game_id -> number of trade offers proposed (last trade ID).
+    TradeCount(u64),
+    /// Maps (game_id, trade_id) -> open TradeOffer.
+    Trade(u64, u32),
+    /// Maps (game_id, square) -> development level (houses, or 5 for a hotel).
+    Development(u64, u32),
+    /// Maps (game_id, square) -> true while the property is mortgaged.
+    Mortgaged(u64, u32),
+    /// Maps (game_id, player) -> failed doubles attempts while in jail.
+    /// Absent when the player is not in jail.
+    Jail(u64, Address),
+    /// tycoon-collectibles contract whose JailFree perks release players.
+    CollectiblesContract,
 }
 
 // -----------------------------------------------------------------------
@@ -34,6 +100,7 @@ pub enum DataKey {
 ///
 /// - `Pending`  — Game created, waiting for players.
 /// - `Ongoing`  — Game is actively being played.
+/// - `Reported` — Result reported, waiting out the dispute window.
 /// - `Ended`    — Game has concluded.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -42,6 +109,8 @@ pub enum GameStatus {
     Pending,
     /// Game is actively being played.
     Ongoing,
+    /// Result reported and awaiting finalization or dispute resolution.
+    Reported,
     /// Game has concluded.
     Ended,
 }
@@ -79,6 +148,17 @@ pub struct GameSettings {
     pub starting_cash: u128,
     /// Room code required to join a private game. Empty string for public games.
     pub private_room_code: String,
+    /// Whether the game starts automatically once the lobby is full.
+    pub auto_start: bool,
+    /// Share of the pot paid to each place, in basis points (1st place first).
+    /// Must sum to 10_000. Empty means winner-takes-all.
+    pub payout_bps: Vec<u32>,
+    /// Seconds after `Game.created_at` before a pending lobby can be expired.
+    /// Zero means the lobby never expires.
+    pub lobby_timeout: u64,
+    /// Seconds the current player has to act before any other player may
+    /// claim a timeout against them. Zero disables turn timeouts.
+    pub turn_timeout: u64,
 }
 
 // -----------------------------------------------------------------------
@@ -117,10 +197,151 @@ pub struct Game {
     pub total_staked: u128,
     /// Ledger timestamp when the game was created.
     pub created_at: u64,
+    /// Ledger timestamp when the game started. Zero while pending.
+    pub started_at: u64,
     /// Ledger timestamp when the game ended. Zero until the game concludes.
     pub ended_at: u64,
 }
 
+// -----------------------------------------------------------------------
+// TurnState struct
+// -----------------------------------------------------------------------
+
+/// Whose turn it is in an ongoing game.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TurnState {
+    /// Index of the current player in the game's turn order.
+    pub current: u32,
+    /// Turn counter, starting at 1 for the first turn.
+    pub turn_number: u32,
+    /// Ledger timestamp when the current turn began.
+    pub turn_started_at: u64,
+    /// Whether the current player has moved their token this turn.
+    pub moved: bool,
+}
+
+// -----------------------------------------------------------------------
+// PendingResult struct
+// -----------------------------------------------------------------------
+
+/// A reported game result held back until its dispute window closes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingResult {
+    /// Reported ranked finishing order, 1st place first.
+    pub placements: Vec<Address>,
+    /// Ledger timestamp when the result was reported.
+    pub reported_at: u64,
+    /// Timestamp after which an undisputed result can be finalized.
+    pub finalizes_at: u64,
+    /// USDC bond required to dispute, fixed when the result is reported.
+    pub bond: u128,
+    /// Participant who disputed the result and posted the bond, if any.
+    pub disputed_by: Option<Address>,
+}
+
+// -----------------------------------------------------------------------
+// DiceRound struct
+// -----------------------------------------------------------------------
+
+/// Commit-reveal state of the dice roll for one turn.
+///
+/// The current player and the backend game controller each commit to
+/// `sha256(seed)`, then reveal their seed, backend first. Once both seeds
+/// are revealed the roll is derived from them. If the reveal deadline passes
+/// first, see `resolve_dice_timeout`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiceRound {
+    /// Commitment from the player whose turn it is.
+    pub player_commit: Option<BytesN<32>>,
+    /// Commitment from the backend game controller.
+    pub backend_commit: Option<BytesN<32>>,
+    /// Player seed, once revealed.
+    pub player_seed: Option<BytesN<32>>,
+    /// Backend seed, once revealed.
+    pub backend_seed: Option<BytesN<32>>,
+    /// Timestamp after which commits and reveals are rejected and the PRNG
+    /// fallback may be used. Set by the first commitment.
+    pub reveal_deadline: u64,
+    /// First die face (1–6). Zero until rolled.
+    pub die1: u32,
+    /// Second die face (1–6). Zero until rolled.
+    pub die2: u32,
+    /// Whether the roll was settled by `resolve_dice_timeout`.
+    pub fallback: bool,
+    /// Whether the player forfeited this turn's move by withholding their
+    /// reveal. The dice stay at zero.
+    pub forfeited: bool,
+}
+
+// -----------------------------------------------------------------------
+// JailRelease enum
+// -----------------------------------------------------------------------
+
+/// How a player got out of jail.
+///
+/// - `Doubles` — rolled doubles.
+/// - `Bail`    — paid `board::JAIL_BAIL`, voluntarily or after the last
+///   failed attempt.
+/// - `Perk`    — burned a tycoon-collectibles JailFree perk.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum JailRelease {
+    Doubles,
+    Bail,
+    Perk,
+}
+
+// -----------------------------------------------------------------------
+// Auction struct
+// -----------------------------------------------------------------------
+
+/// An ascending auction for an unowned property, paid in in-game cash.
+///
+/// The leading bid is escrowed from the bidder's cash and refunded when
+/// outbid.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Auction {
+    /// Board square being auctioned.
+    pub square: u32,
+    /// Player barred from bidding (the one who declined to buy), if any.
+    pub excluded: Option<Address>,
+    /// Current leading bidder, if any.
+    pub highest_bidder: Option<Address>,
+    /// Current leading bid. Zero until the first bid.
+    pub highest_bid: u128,
+    /// Timestamp at which bidding closes.
+    pub ends_at: u64,
+}
+
+// -----------------------------------------------------------------------
+// TradeOffer struct
+// -----------------------------------------------------------------------
+
+/// A proposed swap of properties and in-game cash between two players.
+///
+/// The offer is only valid during the turn it was proposed in.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TradeOffer {
+    pub id: u32,
+    pub proposer: Address,
+    pub counterparty: Address,
+    /// Squares the proposer hands over.
+    pub give_squares: Vec<u32>,
+    /// Cash the proposer hands over.
+    pub give_cash: u128,
+    /// Squares the proposer receives.
+    pub want_squares: Vec<u32>,
+    /// Cash the proposer receives.
+    pub want_cash: u128,
+    /// `TurnState.turn_number` the offer was made in.
+    pub turn_number: u32,
+}
+
 // -----------------------------------------------------------------------
 // Initialization helpers
 // -----------------------------------------------------------------------
@@ -185,6 +406,24 @@ pub fn set_usdc_token(env: &Env, address: &Address) {
     env.storage().instance().set(&DataKey::UsdcToken, address);
 }
 
+// -----------------------------------------------------------------------
+// Backend game controller helpers
+// -----------------------------------------------------------------------
+
+/// Retrieves the backend game controller address, if one has been set.
+pub fn get_backend_game_controller(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::BackendGameController)
+}
+
+/// Stores the backend game controller address.
+pub fn set_backend_game_controller(env: &Env, address: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::BackendGameController, address);
+}
+
 // -----------------------------------------------------------------------
 // Player registration helpers
 // -----------------------------------------------------------------------
@@ -202,6 +441,411 @@ pub fn set_registered(env: &Env, address: &Address) {
         .set(&DataKey::Registered(address.clone()), &true);
 }
 
+/// Retrieves the username of a registered player. Returns `None` if not set.
+pub fn get_username(env: &Env, address: &Address) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Username(address.clone()))
+}
+
+/// Stores the username of a registered player.
+pub fn set_username(env: &Env, address: &Address, username: &String) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Username(address.clone()), username);
+}
+
+// -----------------------------------------------------------------------
+// Registration voucher helpers
+// -----------------------------------------------------------------------
+
+/// Default welcome voucher value: zero, so no voucher is minted until the
+/// owner opts in. The reward system has a single backend minter, so only one
+/// of tycoon-game and this contract can mint vouchers.
+pub const DEFAULT_REGISTRATION_VOUCHER_VALUE: u128 = 0;
+
+/// Returns the TYC value of the registration voucher. Defaults to zero.
+pub fn get_registration_voucher_value(env: &Env) -> u128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::RegistrationVoucherValue)
+        .unwrap_or(DEFAULT_REGISTRATION_VOUCHER_VALUE)
+}
+
+/// Stores the TYC value of the registration voucher.
+pub fn set_registration_voucher_value(env: &Env, value: u128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RegistrationVoucherValue, &value);
+}
+
+// -----------------------------------------------------------------------
+// Settlement deadline helpers
+// -----------------------------------------------------------------------
+
+/// Default settlement timeout: 7 days.
+pub const DEFAULT_SETTLEMENT_TIMEOUT: u64 = 7 * 24 * 60 * 60;
+
+/// Returns the settlement timeout in seconds. Defaults to 7 days.
+pub fn get_settlement_timeout(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SettlementTimeout)
+        .unwrap_or(DEFAULT_SETTLEMENT_TIMEOUT)
+}
+
+/// Stores the settlement timeout in seconds.
+pub fn set_settlement_timeout(env: &Env, timeout: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SettlementTimeout, &timeout);
+}
+
+/// Retrieves the settlement deadline for a game. Returns `None` until the
+/// game starts.
+pub fn get_settlement_deadline(env: &Env, game_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SettlementDeadline(game_id))
+}
+
+/// Persists the settlement deadline for a game.
+pub fn set_settlement_deadline(env: &Env, game_id: u64, deadline: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SettlementDeadline(game_id), &deadline);
+}
+
+// -----------------------------------------------------------------------
+// Signed result helpers
+// -----------------------------------------------------------------------
+
+/// Returns the ed25519 public key of the result signer, if set.
+pub fn get_result_signer(env: &Env) -> Option<BytesN<32>> {
+    env.storage().instance().get(&DataKey::ResultSigner)
+}
+
+/// Stores the ed25519 public key of the result signer.
+pub fn set_result_signer(env: &Env, public_key: &BytesN<32>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ResultSigner, public_key);
+}
+
+/// Returns the last signed-result nonce accepted for a game. Defaults to zero.
+pub fn get_result_nonce(env: &Env, game_id: u64) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ResultNonce(game_id))
+        .unwrap_or(0)
+}
+
+/// Records the last signed-result nonce accepted for a game.
+pub fn set_result_nonce(env: &Env, game_id: u64, nonce: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ResultNonce(game_id), &nonce);
+}
+
+/// Retrieves the final state hash attested for a game, if any.
+pub fn get_result_state_hash(env: &Env, game_id: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ResultStateHash(game_id))
+}
+
+/// Persists the final state hash attested for a game.
+pub fn set_result_state_hash(env: &Env, game_id: u64, state_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ResultStateHash(game_id), state_hash);
+}
+
+// -----------------------------------------------------------------------
+// Dispute helpers
+// -----------------------------------------------------------------------
+
+/// Returns the dispute window in seconds. Defaults to zero (disabled).
+pub fn get_dispute_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DisputeWindow)
+        .unwrap_or(0)
+}
+
+/// Stores the dispute window in seconds.
+pub fn set_dispute_window(env: &Env, window: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::DisputeWindow, &window);
+}
+
+/// Returns the dispute bond in USDC. Defaults to zero.
+pub fn get_dispute_bond(env: &Env) -> u128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DisputeBond)
+        .unwrap_or(0)
+}
+
+/// Stores the dispute bond in USDC.
+pub fn set_dispute_bond(env: &Env, bond: u128) {
+    env.storage().instance().set(&DataKey::DisputeBond, &bond);
+}
+
+/// Returns the dispute arbiter address, if set.
+pub fn get_arbiter(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Arbiter)
+}
+
+/// Stores the dispute arbiter address.
+pub fn set_arbiter(env: &Env, arbiter: &Address) {
+    env.storage().instance().set(&DataKey::Arbiter, arbiter);
+}
+
+/// Retrieves the pending result for a game, if one is awaiting finalization.
+pub fn get_pending_result(env: &Env, game_id: u64) -> Option<PendingResult> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PendingResult(game_id))
+}
+
+/// Persists the pending result for a game.
+pub fn set_pending_result(env: &Env, game_id: u64, result: &PendingResult) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PendingResult(game_id), result);
+}
+
+/// Removes the pending result for a game once it is finalized.
+pub fn remove_pending_result(env: &Env, game_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PendingResult(game_id));
+}
+
+// -----------------------------------------------------------------------
+// Dice helpers
+// -----------------------------------------------------------------------
+
+/// Retrieves the dice round for a game turn, if one was started.
+pub fn get_dice_round(env: &Env, game_id: u64, turn_number: u32) -> Option<DiceRound> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DiceRound(game_id, turn_number))
+}
+
+/// Persists the dice round for a game turn.
+pub fn set_dice_round(env: &Env, game_id: u64, turn_number: u32, round: &DiceRound) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DiceRound(game_id, turn_number), round);
+}
+
+// -----------------------------------------------------------------------
+// Board ledger helpers
+// -----------------------------------------------------------------------
+
+/// Returns a player's in-game cash balance. Defaults to zero.
+pub fn get_cash(env: &Env, game_id: u64, player: &Address) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Cash(game_id, player.clone()))
+        .unwrap_or(0)
+}
+
+/// Stores a player's in-game cash balance.
+pub fn set_cash(env: &Env, game_id: u64, player: &Address, amount: u128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Cash(game_id, player.clone()), &amount);
+}
+
+/// Returns the owner of a board square, if it has been bought.
+pub fn get_property_owner(env: &Env, game_id: u64, square: u32) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PropertyOwner(game_id, square))
+}
+
+/// Stores the owner of a board square.
+pub fn set_property_owner(env: &Env, game_id: u64, square: u32, owner: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PropertyOwner(game_id, square), owner);
+}
+
+/// Clears the owner of a board square, returning it to the bank.
+pub fn remove_property_owner(env: &Env, game_id: u64, square: u32) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PropertyOwner(game_id, square));
+}
+
+/// Returns the squares a player owns. Defaults to empty.
+pub fn get_player_properties(env: &Env, game_id: u64, player: &Address) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PlayerProperties(game_id, player.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores the squares a player owns.
+pub fn set_player_properties(env: &Env, game_id: u64, player: &Address, squares: &Vec<u32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PlayerProperties(game_id, player.clone()), squares);
+}
+
+/// Returns the square a player's token is on. Defaults to Go (0).
+pub fn get_position(env: &Env, game_id: u64, player: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Position(game_id, player.clone()))
+        .unwrap_or(0)
+}
+
+/// Stores the square a player's token is on.
+pub fn set_position(env: &Env, game_id: u64, player: &Address, square: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Position(game_id, player.clone()), &square);
+}
+
+// -----------------------------------------------------------------------
+// Development helpers
+// -----------------------------------------------------------------------
+
+/// Returns a property's development level. Defaults to 0 (undeveloped).
+pub fn get_development(env: &Env, game_id: u64, square: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Development(game_id, square))
+        .unwrap_or(0)
+}
+
+/// Stores a property's development level.
+pub fn set_development(env: &Env, game_id: u64, square: u32, level: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Development(game_id, square), &level);
+}
+
+/// Whether a property is mortgaged. Defaults to false.
+pub fn is_mortgaged(env: &Env, game_id: u64, square: u32) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Mortgaged(game_id, square))
+        .unwrap_or(false)
+}
+
+/// Marks a property as mortgaged or clears the mortgage.
+pub fn set_mortgaged(env: &Env, game_id: u64, square: u32, mortgaged: bool) {
+    let key = DataKey::Mortgaged(game_id, square);
+    if mortgaged {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+// -----------------------------------------------------------------------
+// Jail helpers
+// -----------------------------------------------------------------------
+
+/// Returns a jailed player's failed doubles attempts, or `None` when the
+/// player is not in jail.
+pub fn get_jail_attempts(env: &Env, game_id: u64, player: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Jail(game_id, player.clone()))
+}
+
+/// Puts a player in jail, or updates their failed attempts.
+pub fn set_jail_attempts(env: &Env, game_id: u64, player: &Address, attempts: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Jail(game_id, player.clone()), &attempts);
+}
+
+/// Releases a player from jail.
+pub fn remove_jail(env: &Env, game_id: u64, player: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Jail(game_id, player.clone()));
+}
+
+/// Retrieves the tycoon-collectibles contract address, if configured.
+pub fn get_collectibles_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::CollectiblesContract)
+}
+
+/// Stores the tycoon-collectibles contract address.
+pub fn set_collectibles_contract(env: &Env, collectibles: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::CollectiblesContract, collectibles);
+}
+
+// -----------------------------------------------------------------------
+// Auction helpers
+// -----------------------------------------------------------------------
+
+/// Retrieves the open auction for a square, if any.
+pub fn get_auction(env: &Env, game_id: u64, square: u32) -> Option<Auction> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Auction(game_id, square))
+}
+
+/// Persists the open auction for a square.
+pub fn set_auction(env: &Env, game_id: u64, auction: &Auction) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Auction(game_id, auction.square), auction);
+}
+
+/// Removes the auction for a square once settled.
+pub fn remove_auction(env: &Env, game_id: u64, square: u32) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Auction(game_id, square));
+}
+
+// -----------------------------------------------------------------------
+// Trade helpers
+// -----------------------------------------------------------------------
+
+/// Increments the game's trade counter and returns the new trade ID,
+/// starting at 1.
+pub fn next_trade_id(env: &Env, game_id: u64) -> u32 {
+    let key = DataKey::TradeCount(game_id);
+    let id: u32 = env.storage().persistent().get(&key).unwrap_or(0) + 1;
+    env.storage().persistent().set(&key, &id);
+    id
+}
+
+/// Retrieves an open trade offer.
+pub fn get_trade(env: &Env, game_id: u64, trade_id: u32) -> Option<TradeOffer> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Trade(game_id, trade_id))
+}
+
+/// Persists an open trade offer.
+pub fn set_trade(env: &Env, game_id: u64, offer: &TradeOffer) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Trade(game_id, offer.id), offer);
+}
+
+/// Removes a trade offer once accepted, rejected or countered.
+pub fn remove_trade(env: &Env, game_id: u64, trade_id: u32) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Trade(game_id, trade_id));
+}
+
 // -----------------------------------------------------------------------
 // Game ID counter
 // -----------------------------------------------------------------------
@@ -251,3 +895,117 @@ pub fn set_game_settings(env: &Env, game_id: u64, settings: &GameSettings) {
         .persistent()
         .set(&DataKey::GameSettings(game_id), settings);
 }
+
+// -----------------------------------------------------------------------
+// Turn order helpers
+// -----------------------------------------------------------------------
+
+/// Retrieves the turn order for a game. Returns `None` until the game starts.
+pub fn get_turn_order(env: &Env, game_id: u64) -> Option<Vec<Address>> {
+    env.storage().persistent().get(&DataKey::TurnOrder(game_id))
+}
+
+/// Persists the turn order for a game.
+pub fn set_turn_order(env: &Env, game_id: u64, order: &Vec<Address>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TurnOrder(game_id), order);
+}
+
+// -----------------------------------------------------------------------
+// Turn state helpers
+// -----------------------------------------------------------------------
+
+/// Retrieves the turn state for a game. Returns `None` until the game starts.
+pub fn get_turn_state(env: &Env, game_id: u64) -> Option<TurnState> {
+    env.storage().persistent().get(&DataKey::TurnState(game_id))
+}
+
+/// Persists the turn state for a game.
+pub fn set_turn_state(env: &Env, game_id: u64, state: &TurnState) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TurnState(game_id), state);
+}
+
+/// Retrieves the timestamp of a player's last action in a game.
+pub fn get_last_action(env: &Env, game_id: u64, player: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LastAction(game_id, player.clone()))
+}
+
+/// Records the timestamp of a player's last action in a game.
+pub fn set_last_action(env: &Env, game_id: u64, player: &Address, timestamp: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::LastAction(game_id, player.clone()), &timestamp);
+}
+
+/// Retrieves the players eliminated from a game, in elimination order.
+pub fn get_eliminated(env: &Env, game_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Eliminated(game_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Persists the players eliminated from a game.
+pub fn set_eliminated(env: &Env, game_id: u64, eliminated: &Vec<Address>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Eliminated(game_id), eliminated);
+}
+
+// -----------------------------------------------------------------------
+// Placement helpers
+// -----------------------------------------------------------------------
+
+/// Retrieves the final ranked placements for a game. Returns `None` until
+/// the game is settled.
+pub fn get_placements(env: &Env, game_id: u64) -> Option<Vec<Address>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Placements(game_id))
+}
+
+/// Persists the final ranked placements for a game.
+pub fn set_placements(env: &Env, game_id: u64, placements: &Vec<Address>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Placements(game_id), placements);
+}
+
+// -----------------------------------------------------------------------
+// Protocol fee and treasury helpers
+// -----------------------------------------------------------------------
+
+/// Returns the protocol fee in basis points. Defaults to zero.
+pub fn get_protocol_fee_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ProtocolFeeBps)
+        .unwrap_or(0)
+}
+
+/// Stores the protocol fee in basis points.
+pub fn set_protocol_fee_bps(env: &Env, fee_bps: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ProtocolFeeBps, &fee_bps);
+}
+
+/// Returns the accrued treasury balance for `token`. Defaults to zero.
+pub fn get_treasury_balance(env: &Env, token: &Address) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TreasuryBalance(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Stores the accrued treasury balance for `token`.
+pub fn set_treasury_balance(env: &Env, token: &Address, amount: u128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TreasuryBalance(token.clone()), &amount);
+}
@@ -1,14 +1,17 @@
 #![cfg(test)]
+#![allow(clippy::bool_assert_comparison, clippy::cloned_ref_to_slice_refs)]
 
 use super::*;
+use crate::attestation::GameResultPayload;
 use crate::storage::{
     get_game, get_game_settings, next_game_id, set_game, set_game_settings, Game, GameMode,
     GameSettings, GameStatus,
 };
+use ed25519_dalek::{Signer, SigningKey};
 use soroban_sdk::{
-    testutils::{Address as _, Events},
+    testutils::{Address as _, Events, Ledger},
     token::{StellarAssetClient, TokenClient},
-    Address, Env, String, Vec,
+    vec, Address, BytesN, Env, String, Vec,
 };
 
 // -----------------------------------------------------------------------
@@ -38,6 +41,10 @@ fn make_settings(env: &Env) -> GameSettings {
         auction: false,
         starting_cash: 1500,
         private_room_code: String::from_str(env, ""),
+        auto_start: false,
+        payout_bps: Vec::new(env),
+        lobby_timeout: 0,
+        turn_timeout: 0,
     }
 }
 
@@ -58,6 +65,7 @@ fn make_game(env: &Env, id: u64, creator: Address) -> Game {
         stake_per_player: 100,
         total_staked: 100,
         created_at: 1_000_000,
+        started_at: 0,
         ended_at: 0,
     }
 }
@@ -90,10 +98,82 @@ fn make_game_with_stake(
         stake_per_player: stake,
         total_staked,
         created_at: 1_000,
+        started_at: 0,
         ended_at: 0,
     }
 }
 
+/// Marks `player` as registered directly in contract storage.
+fn register(env: &Env, contract_id: &Address, player: &Address) {
+    env.as_contract(contract_id, || {
+        storage::set_registered(env, player);
+    });
+}
+
+/// Generates a registered player holding `usdc` units of the stake token.
+fn new_player(env: &Env, contract_id: &Address, usdc_token: &Address, usdc: i128) -> Address {
+    let player = Address::generate(env);
+    register(env, contract_id, &player);
+    if usdc > 0 {
+        StellarAssetClient::new(env, usdc_token).mint(&player, &usdc);
+    }
+    player
+}
+
+/// Creates a public game through the contract with the given stake.
+fn create_public_game(
+    env: &Env,
+    client: &TycoonMainGameClient<'_>,
+    creator: &Address,
+    stake: u128,
+) -> u64 {
+    client.create_game(
+        creator,
+        &String::from_str(env, "ABC123"),
+        &GameMode::Public,
+        &false,
+        &stake,
+        &make_settings(env),
+    )
+}
+
+/// Minimal stand-in for the reward system's `mint_voucher` entry point.
+/// Records the last voucher value minted to each recipient.
+#[soroban_sdk::contract]
+pub struct MockRewardSystem;
+
+#[soroban_sdk::contractimpl]
+impl MockRewardSystem {
+    pub fn mint_voucher(env: Env, caller: Address, to: Address, tyc_value: u128) -> u128 {
+        caller.require_auth();
+        env.storage().persistent().set(&to, &tyc_value);
+        1_000_000_000
+    }
+
+    pub fn voucher_value(env: Env, to: Address) -> u128 {
+        env.storage().persistent().get(&to).unwrap_or(0)
+    }
+}
+
+/// Like `setup_contract`, but initializes against a `MockRewardSystem`.
+/// Returns (contract_id, client, reward_system client).
+fn setup_with_reward_system(
+    env: &Env,
+) -> (
+    Address,
+    TycoonMainGameClient<'_>,
+    MockRewardSystemClient<'_>,
+) {
+    let (contract_id, client, owner, _, usdc_token) = setup_contract(env);
+    let reward_system = env.register(MockRewardSystem, ());
+    client.initialize(&owner, &reward_system, &usdc_token);
+    (
+        contract_id,
+        client,
+        MockRewardSystemClient::new(env, &reward_system),
+    )
+}
+
 // -----------------------------------------------------------------------
 // Existing: GameSettings struct tests
 // -----------------------------------------------------------------------
@@ -127,6 +207,10 @@ fn test_game_settings_private_room_code_stored() {
         auction: true,
         starting_cash: 2000,
         private_room_code: String::from_str(&env, "SECRET99"),
+        auto_start: false,
+        payout_bps: Vec::new(&env),
+        lobby_timeout: 0,
+        turn_timeout: 0,
     };
 
     env.as_contract(&contract_id, || {
@@ -165,6 +249,10 @@ fn test_game_settings_overwrite() {
             auction: false,
             starting_cash: 1500,
             private_room_code: String::from_str(&env, ""),
+            auto_start: false,
+            payout_bps: Vec::new(&env),
+            lobby_timeout: 0,
+            turn_timeout: 0,
         };
         set_game_settings(&env, 1, &v1);
 
@@ -173,6 +261,10 @@ fn test_game_settings_overwrite() {
             auction: true,
             starting_cash: 3000,
             private_room_code: String::from_str(&env, "NEWCODE"),
+            auto_start: false,
+            payout_bps: Vec::new(&env),
+            lobby_timeout: 0,
+            turn_timeout: 0,
         };
         set_game_settings(&env, 1, &v2);
 
@@ -304,6 +396,7 @@ fn test_game_joined_players_stored_correctly() {
         stake_per_player: 0,
         total_staked: 0,
         created_at: 1_000,
+        started_at: 0,
         ended_at: 0,
     };
 
@@ -415,6 +508,10 @@ fn test_game_and_settings_stored_independently_for_same_id() {
         auction: true,
         starting_cash: 2000,
         private_room_code: String::from_str(&env, "ROOM1"),
+        auto_start: false,
+        payout_bps: Vec::new(&env),
+        lobby_timeout: 0,
+        turn_timeout: 0,
     };
 
     env.as_contract(&contract_id, || {
@@ -803,3 +900,3969 @@ fn test_leave_pending_game_non_member_panics() {
 
     client.leave_pending_game(&1, &outsider);
 }
+
+// -----------------------------------------------------------------------
+// create_game
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_create_game_stores_game_and_settings() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    register(&env, &contract_id, &creator);
+
+    let game_id = client.create_game(
+        &creator,
+        &String::from_str(&env, "ABC123"),
+        &GameMode::Public,
+        &false,
+        &0,
+        &make_settings(&env),
+    );
+
+    assert_eq!(game_id, 1);
+    let game = client.get_game(&game_id).unwrap();
+    assert_eq!(game.creator, creator);
+    assert_eq!(game.status, GameStatus::Pending);
+    assert_eq!(game.number_of_players, 4);
+    assert_eq!(game.joined_players.len(), 1);
+    assert_eq!(game.joined_players.get(0), Some(creator));
+    assert_eq!(game.total_staked, 0);
+    assert_eq!(
+        client.get_game_settings(&game_id),
+        Some(make_settings(&env))
+    );
+}
+
+#[test]
+fn test_create_game_escrows_creator_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    register(&env, &contract_id, &creator);
+    StellarAssetClient::new(&env, &usdc_token).mint(&creator, &1_000);
+
+    let game_id = client.create_game(
+        &creator,
+        &String::from_str(&env, "ABC123"),
+        &GameMode::Public,
+        &false,
+        &400,
+        &make_settings(&env),
+    );
+
+    let token = TokenClient::new(&env, &usdc_token);
+    assert_eq!(token.balance(&creator), 600);
+    assert_eq!(token.balance(&contract_id), 400);
+
+    let game = client.get_game(&game_id).unwrap();
+    assert_eq!(game.stake_per_player, 400);
+    assert_eq!(game.total_staked, 400);
+}
+
+#[test]
+fn test_create_game_assigns_sequential_ids_and_emits_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    register(&env, &contract_id, &creator);
+
+    let code = String::from_str(&env, "ABC123");
+    let first = client.create_game(
+        &creator,
+        &code,
+        &GameMode::Public,
+        &false,
+        &0,
+        &make_settings(&env),
+    );
+    assert!(!env.events().all().is_empty());
+    let second = client.create_game(
+        &creator,
+        &code,
+        &GameMode::Public,
+        &false,
+        &0,
+        &make_settings(&env),
+    );
+
+    assert_eq!(first, 1);
+    assert_eq!(second, 2);
+}
+
+#[test]
+fn test_create_private_game_with_room_code() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    register(&env, &contract_id, &creator);
+
+    let mut settings = make_settings(&env);
+    settings.private_room_code = String::from_str(&env, "SECRET");
+
+    let game_id = client.create_game(
+        &creator,
+        &String::from_str(&env, "ABC123"),
+        &GameMode::Private,
+        &false,
+        &0,
+        &settings,
+    );
+
+    assert_eq!(client.get_game(&game_id).unwrap().mode, GameMode::Private);
+}
+
+#[test]
+#[should_panic(expected = "Player is not registered")]
+fn test_create_game_unregistered_creator_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    client.create_game(
+        &Address::generate(&env),
+        &String::from_str(&env, "ABC123"),
+        &GameMode::Public,
+        &false,
+        &0,
+        &make_settings(&env),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Max players must be between 2 and 8")]
+fn test_create_game_too_few_players_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    register(&env, &contract_id, &creator);
+
+    let mut settings = make_settings(&env);
+    settings.max_players = 1;
+
+    client.create_game(
+        &creator,
+        &String::from_str(&env, "ABC123"),
+        &GameMode::Public,
+        &false,
+        &0,
+        &settings,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Max players must be between 2 and 8")]
+fn test_create_game_too_many_players_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    register(&env, &contract_id, &creator);
+
+    let mut settings = make_settings(&env);
+    settings.max_players = 9;
+
+    client.create_game(
+        &creator,
+        &String::from_str(&env, "ABC123"),
+        &GameMode::Public,
+        &false,
+        &0,
+        &settings,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Private game requires a room code")]
+fn test_create_private_game_without_code_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    register(&env, &contract_id, &creator);
+
+    client.create_game(
+        &creator,
+        &String::from_str(&env, "ABC123"),
+        &GameMode::Private,
+        &false,
+        &0,
+        &make_settings(&env),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Public game cannot have a room code")]
+fn test_create_public_game_with_code_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    register(&env, &contract_id, &creator);
+
+    let mut settings = make_settings(&env);
+    settings.private_room_code = String::from_str(&env, "SECRET");
+
+    client.create_game(
+        &creator,
+        &String::from_str(&env, "ABC123"),
+        &GameMode::Public,
+        &false,
+        &0,
+        &settings,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Stake amount too large")]
+fn test_create_game_overflowing_stake_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    register(&env, &contract_id, &creator);
+
+    client.create_game(
+        &creator,
+        &String::from_str(&env, "ABC123"),
+        &GameMode::Public,
+        &false,
+        &(i128::MAX as u128),
+        &make_settings(&env),
+    );
+}
+
+// -----------------------------------------------------------------------
+// join_game
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_join_game_adds_player_and_collects_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = new_player(&env, &contract_id, &usdc_token, 500);
+    let player2 = new_player(&env, &contract_id, &usdc_token, 500);
+    let game_id = create_public_game(&env, &client, &creator, 200);
+
+    client.join_game(&game_id, &player2, &String::from_str(&env, ""));
+
+    let game = client.get_game(&game_id).unwrap();
+    assert_eq!(game.joined_players.len(), 2);
+    assert_eq!(game.joined_players.get(1), Some(player2.clone()));
+    assert_eq!(game.total_staked, 400);
+
+    let token = TokenClient::new(&env, &usdc_token);
+    assert_eq!(token.balance(&player2), 300);
+    assert_eq!(token.balance(&contract_id), 400);
+}
+
+#[test]
+fn test_join_game_emits_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = new_player(&env, &contract_id, &usdc_token, 0);
+    let player2 = new_player(&env, &contract_id, &usdc_token, 0);
+    let game_id = create_public_game(&env, &client, &creator, 0);
+
+    client.join_game(&game_id, &player2, &String::from_str(&env, ""));
+
+    assert!(!env.events().all().is_empty());
+}
+
+#[test]
+fn test_join_private_game_with_matching_code() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = new_player(&env, &contract_id, &usdc_token, 0);
+    let player2 = new_player(&env, &contract_id, &usdc_token, 0);
+
+    let mut settings = make_settings(&env);
+    settings.private_room_code = String::from_str(&env, "SECRET");
+    let game_id = client.create_game(
+        &creator,
+        &String::from_str(&env, "ABC123"),
+        &GameMode::Private,
+        &false,
+        &0,
+        &settings,
+    );
+
+    client.join_game(&game_id, &player2, &String::from_str(&env, "SECRET"));
+
+    assert_eq!(client.get_game(&game_id).unwrap().joined_players.len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Invalid room code")]
+fn test_join_private_game_wrong_code_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = new_player(&env, &contract_id, &usdc_token, 0);
+    let player2 = new_player(&env, &contract_id, &usdc_token, 0);
+
+    let mut settings = make_settings(&env);
+    settings.private_room_code = String::from_str(&env, "SECRET");
+    let game_id = client.create_game(
+        &creator,
+        &String::from_str(&env, "ABC123"),
+        &GameMode::Private,
+        &false,
+        &0,
+        &settings,
+    );
+
+    client.join_game(&game_id, &player2, &String::from_str(&env, "WRONG"));
+}
+
+#[test]
+#[should_panic(expected = "Player is not registered")]
+fn test_join_game_unregistered_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = new_player(&env, &contract_id, &usdc_token, 0);
+    let game_id = create_public_game(&env, &client, &creator, 0);
+
+    client.join_game(
+        &game_id,
+        &Address::generate(&env),
+        &String::from_str(&env, ""),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Game not found")]
+fn test_join_game_unknown_game_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let player = new_player(&env, &contract_id, &usdc_token, 0);
+    client.join_game(&999, &player, &String::from_str(&env, ""));
+}
+
+#[test]
+#[should_panic(expected = "Game is not pending")]
+fn test_join_game_not_pending_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = new_player(&env, &contract_id, &usdc_token, 0);
+    let player2 = new_player(&env, &contract_id, &usdc_token, 0);
+    let game_id = create_public_game(&env, &client, &creator, 0);
+
+    env.as_contract(&contract_id, || {
+        let mut game = get_game(&env, game_id).unwrap();
+        game.status = GameStatus::Ongoing;
+        set_game(&env, &game);
+    });
+
+    client.join_game(&game_id, &player2, &String::from_str(&env, ""));
+}
+
+#[test]
+#[should_panic(expected = "Game is full")]
+fn test_join_game_full_lobby_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = new_player(&env, &contract_id, &usdc_token, 0);
+    let mut settings = make_settings(&env);
+    settings.max_players = 2;
+    let game_id = client.create_game(
+        &creator,
+        &String::from_str(&env, "ABC123"),
+        &GameMode::Public,
+        &false,
+        &0,
+        &settings,
+    );
+
+    let empty = String::from_str(&env, "");
+    client.join_game(
+        &game_id,
+        &new_player(&env, &contract_id, &usdc_token, 0),
+        &empty,
+    );
+    client.join_game(
+        &game_id,
+        &new_player(&env, &contract_id, &usdc_token, 0),
+        &empty,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Player already joined")]
+fn test_join_game_twice_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = new_player(&env, &contract_id, &usdc_token, 0);
+    let game_id = create_public_game(&env, &client, &creator, 0);
+
+    client.join_game(&game_id, &creator, &String::from_str(&env, ""));
+}
+
+#[test]
+fn test_join_then_leave_refunds_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = new_player(&env, &contract_id, &usdc_token, 300);
+    let player2 = new_player(&env, &contract_id, &usdc_token, 300);
+    let game_id = create_public_game(&env, &client, &creator, 300);
+
+    client.join_game(&game_id, &player2, &String::from_str(&env, ""));
+    client.leave_pending_game(&game_id, &player2);
+
+    let token = TokenClient::new(&env, &usdc_token);
+    assert_eq!(token.balance(&player2), 300);
+    assert_eq!(token.balance(&contract_id), 300);
+    assert_eq!(client.get_game(&game_id).unwrap().total_staked, 300);
+}
+
+// -----------------------------------------------------------------------
+// start_game
+// -----------------------------------------------------------------------
+
+/// Creates a public game with `settings`, staking `stake` per player, and
+/// fills it with `extra` joiners. Returns (game_id, creator, joiners).
+fn create_game_with_settings(
+    env: &Env,
+    client: &TycoonMainGameClient<'_>,
+    contract_id: &Address,
+    usdc_token: &Address,
+    stake: u128,
+    extra: u32,
+    settings: &GameSettings,
+) -> (u64, Address, Vec<Address>) {
+    let creator = new_player(env, contract_id, usdc_token, stake as i128);
+    let game_id = client.create_game(
+        &creator,
+        &String::from_str(env, "ABC123"),
+        &GameMode::Public,
+        &false,
+        &stake,
+        settings,
+    );
+
+    let mut players = Vec::new(env);
+    for _ in 0..extra {
+        let player = new_player(env, contract_id, usdc_token, stake as i128);
+        client.join_game(&game_id, &player, &String::from_str(env, ""));
+        players.push_back(player);
+    }
+
+    (game_id, creator, players)
+}
+
+/// Like `create_game_with_settings`, but also starts the game.
+/// Returns (game_id, turn_order).
+fn start_game_with_settings(
+    env: &Env,
+    client: &TycoonMainGameClient<'_>,
+    contract_id: &Address,
+    usdc_token: &Address,
+    stake: u128,
+    extra: u32,
+    settings: &GameSettings,
+) -> (u64, Vec<Address>) {
+    let (game_id, creator, _) =
+        create_game_with_settings(env, client, contract_id, usdc_token, stake, extra, settings);
+    client.start_game(&game_id, &creator);
+
+    (game_id, client.get_turn_order(&game_id).unwrap())
+}
+
+#[test]
+fn test_start_game_by_creator() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(5_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let settings = make_settings(&env);
+    let (game_id, creator, _) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 2, &settings);
+
+    client.start_game(&game_id, &creator);
+
+    let game = client.get_game(&game_id).unwrap();
+    assert_eq!(game.status, GameStatus::Ongoing);
+    assert_eq!(game.started_at, 5_000);
+
+    let order = client.get_turn_order(&game_id).unwrap();
+    assert_eq!(order.len(), 3);
+    for p in game.joined_players.iter() {
+        assert!(order.contains(&p));
+    }
+}
+
+#[test]
+fn test_start_game_by_backend_controller() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let controller = Address::generate(&env);
+    client.set_backend_game_controller(&controller);
+    assert_eq!(
+        client.get_backend_game_controller(),
+        Some(controller.clone())
+    );
+
+    let settings = make_settings(&env);
+    let (game_id, _, _) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 1, &settings);
+
+    client.start_game(&game_id, &controller);
+
+    assert_eq!(
+        client.get_game(&game_id).unwrap().status,
+        GameStatus::Ongoing
+    );
+}
+
+#[test]
+fn test_start_game_emits_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let settings = make_settings(&env);
+    let (game_id, creator, _) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 1, &settings);
+
+    client.start_game(&game_id, &creator);
+
+    assert!(!env.events().all().is_empty());
+}
+
+#[test]
+fn test_auto_start_when_lobby_fills() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = new_player(&env, &contract_id, &usdc_token, 0);
+    let mut settings = make_settings(&env);
+    settings.max_players = 2;
+    settings.auto_start = true;
+    let game_id = client.create_game(
+        &creator,
+        &String::from_str(&env, "ABC123"),
+        &GameMode::Public,
+        &false,
+        &0,
+        &settings,
+    );
+
+    let player2 = new_player(&env, &contract_id, &usdc_token, 0);
+    client.join_game(&game_id, &player2, &String::from_str(&env, ""));
+
+    assert_eq!(
+        client.get_game(&game_id).unwrap().status,
+        GameStatus::Ongoing
+    );
+    assert_eq!(client.get_turn_order(&game_id).unwrap().len(), 2);
+}
+
+#[test]
+fn test_full_lobby_without_auto_start_stays_pending() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let settings = make_settings(&env);
+    let (game_id, _, _) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 3, &settings);
+
+    assert_eq!(
+        client.get_game(&game_id).unwrap().status,
+        GameStatus::Pending
+    );
+    assert!(client.get_turn_order(&game_id).is_none());
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller must be creator or backend game controller")]
+fn test_start_game_unauthorized_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let settings = make_settings(&env);
+    let (game_id, _, players) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 1, &settings);
+
+    client.start_game(&game_id, &players.get(0).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Not enough players to start")]
+fn test_start_game_single_player_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let settings = make_settings(&env);
+    let (game_id, creator, _) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 0, &settings);
+
+    client.start_game(&game_id, &creator);
+}
+
+#[test]
+#[should_panic(expected = "Game is not pending")]
+fn test_start_game_twice_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let settings = make_settings(&env);
+    let (game_id, creator, _) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 1, &settings);
+
+    client.start_game(&game_id, &creator);
+    client.start_game(&game_id, &creator);
+}
+
+#[test]
+#[should_panic(expected = "Game is not pending")]
+fn test_join_started_game_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let settings = make_settings(&env);
+    let (game_id, creator, _) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 1, &settings);
+    client.start_game(&game_id, &creator);
+
+    let late = new_player(&env, &contract_id, &usdc_token, 0);
+    client.join_game(&game_id, &late, &String::from_str(&env, ""));
+}
+
+// -----------------------------------------------------------------------
+// end_game
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_end_game_pays_pot_to_winner() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(10_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let settings = make_settings(&env);
+    let (game_id, creator, players) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 250, 2, &settings);
+    client.start_game(&game_id, &creator);
+
+    let winner = players.get(1).unwrap();
+    client.end_game(&game_id, &owner, &vec![&env, winner.clone()]);
+
+    let token = TokenClient::new(&env, &usdc_token);
+    assert_eq!(token.balance(&winner), 750);
+    assert_eq!(token.balance(&contract_id), 0);
+
+    let game = client.get_game(&game_id).unwrap();
+    assert_eq!(game.status, GameStatus::Ended);
+    assert_eq!(game.winner, Some(winner));
+    assert_eq!(game.ended_at, 10_000);
+    assert_eq!(game.total_staked, 0);
+}
+
+#[test]
+fn test_end_game_by_backend_controller() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let controller = Address::generate(&env);
+    client.set_backend_game_controller(&controller);
+
+    let settings = make_settings(&env);
+    let (game_id, creator, _) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 100, 1, &settings);
+    client.start_game(&game_id, &creator);
+
+    client.end_game(&game_id, &controller, &vec![&env, creator.clone()]);
+    assert!(!env.events().all().is_empty());
+
+    assert_eq!(TokenClient::new(&env, &usdc_token).balance(&creator), 200);
+}
+
+#[test]
+fn test_end_game_free_game_no_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let settings = make_settings(&env);
+    let (game_id, creator, _) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 1, &settings);
+    client.start_game(&game_id, &creator);
+
+    client.end_game(&game_id, &owner, &vec![&env, creator.clone()]);
+
+    assert_eq!(client.get_game(&game_id).unwrap().winner, Some(creator));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller must be owner or backend game controller")]
+fn test_end_game_by_creator_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let settings = make_settings(&env);
+    let (game_id, creator, _) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 1, &settings);
+    client.start_game(&game_id, &creator);
+
+    client.end_game(&game_id, &creator, &vec![&env, creator.clone()]);
+}
+
+#[test]
+#[should_panic(expected = "Game is not ongoing")]
+fn test_end_game_pending_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let settings = make_settings(&env);
+    let (game_id, creator, _) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 1, &settings);
+
+    client.end_game(&game_id, &owner, &vec![&env, creator.clone()]);
+}
+
+#[test]
+#[should_panic(expected = "Game is not ongoing")]
+fn test_end_game_twice_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let settings = make_settings(&env);
+    let (game_id, creator, _) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 100, 1, &settings);
+    client.start_game(&game_id, &creator);
+
+    client.end_game(&game_id, &owner, &vec![&env, creator.clone()]);
+    client.end_game(&game_id, &owner, &vec![&env, creator.clone()]);
+}
+
+#[test]
+#[should_panic(expected = "Placement is not in this game")]
+fn test_end_game_outsider_winner_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let settings = make_settings(&env);
+    let (game_id, creator, _) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 1, &settings);
+    client.start_game(&game_id, &creator);
+
+    client.end_game(&game_id, &owner, &vec![&env, Address::generate(&env)]);
+}
+
+#[test]
+#[should_panic(expected = "Game not found")]
+fn test_end_game_unknown_game_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    client.end_game(&999, &owner, &vec![&env, Address::generate(&env)]);
+}
+
+// -----------------------------------------------------------------------
+// end_game — multi-place payouts
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_end_game_splits_pot_70_20_10() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let mut settings = make_settings(&env);
+    settings.max_players = 8;
+    settings.payout_bps = vec![&env, 7_000, 2_000, 1_000];
+    let (game_id, players) = start_game_with_settings(
+        &env,
+        &client,
+        &contract_id,
+        &usdc_token,
+        1_000,
+        5,
+        &settings,
+    );
+
+    let first = players.get(3).unwrap();
+    let second = players.get(0).unwrap();
+    let third = players.get(5).unwrap();
+    let placements = vec![&env, first.clone(), second.clone(), third.clone()];
+    client.end_game(&game_id, &owner, &placements);
+
+    let token = TokenClient::new(&env, &usdc_token);
+    assert_eq!(token.balance(&first), 4_200);
+    assert_eq!(token.balance(&second), 1_200);
+    assert_eq!(token.balance(&third), 600);
+    assert_eq!(token.balance(&players.get(1).unwrap()), 0);
+    assert_eq!(token.balance(&contract_id), 0);
+
+    let game = client.get_game(&game_id).unwrap();
+    assert_eq!(game.winner, Some(first));
+    assert_eq!(client.get_placements(&game_id), Some(placements));
+}
+
+#[test]
+fn test_end_game_rounding_dust_goes_to_first_place() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    // Pot of 3 * 333 = 999 split 3333/3333/3334
+    let mut settings = make_settings(&env);
+    settings.payout_bps = vec![&env, 3_333, 3_333, 3_334];
+    let (game_id, players) =
+        start_game_with_settings(&env, &client, &contract_id, &usdc_token, 333, 2, &settings);
+
+    client.end_game(&game_id, &owner, &players);
+
+    let token = TokenClient::new(&env, &usdc_token);
+    let first = token.balance(&players.get(0).unwrap());
+    let second = token.balance(&players.get(1).unwrap());
+    let third = token.balance(&players.get(2).unwrap());
+    assert_eq!(second, 332);
+    assert_eq!(third, 333);
+    assert_eq!(first, 999 - 332 - 333);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_end_game_extra_placements_receive_nothing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let mut settings = make_settings(&env);
+    settings.payout_bps = Vec::new(&env);
+    let (game_id, players) =
+        start_game_with_settings(&env, &client, &contract_id, &usdc_token, 100, 2, &settings);
+
+    client.end_game(&game_id, &owner, &players);
+
+    let token = TokenClient::new(&env, &usdc_token);
+    assert_eq!(token.balance(&players.get(0).unwrap()), 300);
+    assert_eq!(token.balance(&players.get(1).unwrap()), 0);
+    assert_eq!(token.balance(&players.get(2).unwrap()), 0);
+}
+
+#[test]
+#[should_panic(expected = "Not enough placements for payout table")]
+fn test_end_game_missing_paid_place_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let mut settings = make_settings(&env);
+    settings.payout_bps = vec![&env, 7_000, 2_000, 1_000];
+    let (game_id, players) =
+        start_game_with_settings(&env, &client, &contract_id, &usdc_token, 100, 2, &settings);
+
+    let placements = vec![&env, players.get(0).unwrap(), players.get(1).unwrap()];
+    client.end_game(&game_id, &owner, &placements);
+}
+
+#[test]
+fn test_end_game_fewer_players_than_paid_places() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let mut settings = make_settings(&env);
+    settings.payout_bps = vec![&env, 7_000, 2_000, 1_000];
+    let (game_id, players) =
+        start_game_with_settings(&env, &client, &contract_id, &usdc_token, 100, 1, &settings);
+
+    let first = players.get(0).unwrap();
+    let second = players.get(1).unwrap();
+    client.end_game(&game_id, &owner, &vec![&env, first.clone(), second.clone()]);
+
+    // Pot 200: the unfilled 3rd-place share goes to 1st
+    let token = TokenClient::new(&env, &usdc_token);
+    assert_eq!(token.balance(&first), 160);
+    assert_eq!(token.balance(&second), 40);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+#[test]
+#[should_panic(expected = "Duplicate placement")]
+fn test_end_game_duplicate_placement_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let mut settings = make_settings(&env);
+    settings.payout_bps = vec![&env, 6_000, 4_000];
+    let (game_id, players) =
+        start_game_with_settings(&env, &client, &contract_id, &usdc_token, 100, 2, &settings);
+
+    let winner = players.get(0).unwrap();
+    client.end_game(&game_id, &owner, &vec![&env, winner.clone(), winner]);
+}
+
+#[test]
+#[should_panic(expected = "Payout table must sum to 10000 bps")]
+fn test_create_game_payout_table_bad_sum_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let mut settings = make_settings(&env);
+    settings.payout_bps = vec![&env, 7_000, 2_000];
+    start_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 1, &settings);
+}
+
+#[test]
+#[should_panic(expected = "Payout table has more places than players")]
+fn test_create_game_payout_table_too_long_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = new_player(&env, &contract_id, &usdc_token, 0);
+    let mut settings = make_settings(&env);
+    settings.max_players = 2;
+    settings.payout_bps = vec![&env, 5_000, 3_000, 2_000];
+
+    client.create_game(
+        &creator,
+        &String::from_str(&env, "ABC123"),
+        &GameMode::Public,
+        &false,
+        &0,
+        &settings,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Payout share must be non-zero")]
+fn test_create_game_payout_table_zero_share_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let mut settings = make_settings(&env);
+    settings.payout_bps = vec![&env, 10_000, 0];
+    start_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 1, &settings);
+}
+
+#[test]
+fn test_split_pot_sums_exactly() {
+    let env = Env::default();
+    let table = vec![&env, 5_000, 3_000, 1_500, 500];
+
+    for pot in [0u128, 1, 7, 9_999, 10_001, 123_456_789, i128::MAX as u128] {
+        let amounts = payout::split_pot(&env, pot, &table);
+        let total: u128 = amounts.iter().sum();
+        assert_eq!(total, pot);
+    }
+}
+
+// -----------------------------------------------------------------------
+// Protocol fee and treasury
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_set_protocol_fee_stores_and_emits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    assert_eq!(client.get_protocol_fee(), 0);
+    client.set_protocol_fee(&250);
+    assert!(!env.events().all().is_empty());
+    assert_eq!(client.get_protocol_fee(), 250);
+}
+
+#[test]
+#[should_panic(expected = "Fee exceeds maximum")]
+fn test_set_protocol_fee_above_cap_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    client.set_protocol_fee(&1_001);
+}
+
+#[test]
+fn test_end_game_takes_protocol_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    client.set_protocol_fee(&500);
+
+    let mut settings = make_settings(&env);
+    settings.payout_bps = vec![&env, 7_000, 3_000];
+    let (game_id, players) = start_game_with_settings(
+        &env,
+        &client,
+        &contract_id,
+        &usdc_token,
+        1_000,
+        3,
+        &settings,
+    );
+
+    client.end_game(&game_id, &owner, &players);
+
+    // Pot 4000, fee 5% = 200, remaining 3800 split 2660 / 1140
+    let token = TokenClient::new(&env, &usdc_token);
+    assert_eq!(token.balance(&players.get(0).unwrap()), 2_660);
+    assert_eq!(token.balance(&players.get(1).unwrap()), 1_140);
+    assert_eq!(token.balance(&contract_id), 200);
+    assert_eq!(client.get_treasury_balance(&usdc_token), 200);
+}
+
+#[test]
+fn test_withdraw_treasury_transfers_accrued_fees() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    client.set_protocol_fee(&1_000);
+
+    let mut settings = make_settings(&env);
+    settings.payout_bps = Vec::new(&env);
+    let (game_id, players) =
+        start_game_with_settings(&env, &client, &contract_id, &usdc_token, 500, 1, &settings);
+    client.end_game(&game_id, &owner, &players);
+
+    let treasury = Address::generate(&env);
+    client.withdraw_treasury(&usdc_token, &treasury, &60);
+    assert!(!env.events().all().is_empty());
+
+    let token = TokenClient::new(&env, &usdc_token);
+    assert_eq!(token.balance(&treasury), 60);
+    assert_eq!(client.get_treasury_balance(&usdc_token), 40);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient treasury balance")]
+fn test_withdraw_treasury_cannot_touch_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    client.set_protocol_fee(&1_000);
+
+    // Stakes are escrowed but no game has settled, so no fees have accrued
+    let mut settings = make_settings(&env);
+    settings.payout_bps = Vec::new(&env);
+    start_game_with_settings(&env, &client, &contract_id, &usdc_token, 500, 1, &settings);
+
+    client.withdraw_treasury(&usdc_token, &owner, &1);
+}
+
+// -----------------------------------------------------------------------
+// cancel_game / expire_game
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_cancel_game_refunds_all_players() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let settings = make_settings(&env);
+    let (game_id, creator, players) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 150, 2, &settings);
+
+    client.cancel_game(&game_id, &creator);
+    assert!(!env.events().all().is_empty());
+
+    let token = TokenClient::new(&env, &usdc_token);
+    assert_eq!(token.balance(&creator), 150);
+    for p in players.iter() {
+        assert_eq!(token.balance(&p), 150);
+    }
+    assert_eq!(token.balance(&contract_id), 0);
+
+    let game = client.get_game(&game_id).unwrap();
+    assert_eq!(game.status, GameStatus::Ended);
+    assert_eq!(game.total_staked, 0);
+}
+
+#[test]
+#[should_panic(expected = "Only the creator can cancel")]
+fn test_cancel_game_by_non_creator_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let settings = make_settings(&env);
+    let (game_id, _, players) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 1, &settings);
+
+    client.cancel_game(&game_id, &players.get(0).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Game is not pending")]
+fn test_cancel_started_game_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let settings = make_settings(&env);
+    let (game_id, creator, _) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 1, &settings);
+    client.start_game(&game_id, &creator);
+
+    client.cancel_game(&game_id, &creator);
+}
+
+#[test]
+fn test_expire_game_after_timeout_refunds_all_players() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let mut settings = make_settings(&env);
+    settings.lobby_timeout = 600;
+    let (game_id, creator, players) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 200, 2, &settings);
+
+    env.ledger().set_timestamp(1_600);
+    client.expire_game(&game_id);
+    assert!(!env.events().all().is_empty());
+
+    let token = TokenClient::new(&env, &usdc_token);
+    assert_eq!(token.balance(&creator), 200);
+    for p in players.iter() {
+        assert_eq!(token.balance(&p), 200);
+    }
+    assert_eq!(token.balance(&contract_id), 0);
+
+    let game = client.get_game(&game_id).unwrap();
+    assert_eq!(game.status, GameStatus::Ended);
+    assert_eq!(game.ended_at, 1_600);
+}
+
+#[test]
+#[should_panic(expected = "Lobby has not expired")]
+fn test_expire_game_before_timeout_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let mut settings = make_settings(&env);
+    settings.lobby_timeout = 600;
+    let (game_id, _, _) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 1, &settings);
+
+    env.ledger().set_timestamp(1_599);
+    client.expire_game(&game_id);
+}
+
+#[test]
+#[should_panic(expected = "Lobby has not expired")]
+fn test_expire_game_without_timeout_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let mut settings = make_settings(&env);
+    settings.lobby_timeout = 0;
+    let (game_id, _, _) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 1, &settings);
+
+    env.ledger().set_timestamp(u64::MAX);
+    client.expire_game(&game_id);
+}
+
+#[test]
+#[should_panic(expected = "Game is not pending")]
+fn test_expire_started_game_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let mut settings = make_settings(&env);
+    settings.lobby_timeout = 600;
+    let (game_id, creator, _) =
+        create_game_with_settings(&env, &client, &contract_id, &usdc_token, 0, 1, &settings);
+    client.start_game(&game_id, &creator);
+
+    env.ledger().set_timestamp(10_000);
+    client.expire_game(&game_id);
+}
+
+// -----------------------------------------------------------------------
+// register_player
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_register_player_marks_registered_and_mints_voucher() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, rewards) = setup_with_reward_system(&env);
+    client.set_registration_voucher_value(&2_0000000);
+
+    let player = Address::generate(&env);
+    client.register_player(&player, &String::from_str(&env, "alice"));
+
+    assert!(client.is_registered(&player));
+    assert_eq!(
+        client.get_username(&player),
+        Some(String::from_str(&env, "alice"))
+    );
+    assert_eq!(rewards.voucher_value(&player), 2_0000000);
+}
+
+#[test]
+fn test_register_player_uses_configured_voucher_value() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, rewards) = setup_with_reward_system(&env);
+
+    client.set_registration_voucher_value(&5_0000000);
+    assert_eq!(client.get_registration_voucher_value(), 5_0000000);
+
+    let player = Address::generate(&env);
+    client.register_player(&player, &String::from_str(&env, "alice"));
+
+    assert_eq!(rewards.voucher_value(&player), 5_0000000);
+}
+
+#[test]
+fn test_register_player_zero_voucher_skips_mint() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    // reward_system is a plain address — any cross-contract call would fail
+    client.initialize(&owner, &reward_system, &usdc_token);
+    client.set_registration_voucher_value(&2_0000000);
+    client.set_registration_voucher_value(&0);
+
+    let player = Address::generate(&env);
+    client.register_player(&player, &String::from_str(&env, "alice"));
+    assert!(!env.events().all().is_empty());
+
+    assert!(client.is_registered(&player));
+}
+
+#[test]
+fn test_register_player_voucher_disabled_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    // reward_system is a plain address — any cross-contract call would fail
+    client.initialize(&owner, &reward_system, &usdc_token);
+    assert_eq!(client.get_registration_voucher_value(), 0);
+
+    let player = Address::generate(&env);
+    client.register_player(&player, &String::from_str(&env, "alice"));
+
+    assert!(client.is_registered(&player));
+}
+
+#[test]
+fn test_registered_player_can_create_game() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _) = setup_with_reward_system(&env);
+
+    let player = Address::generate(&env);
+    client.register_player(&player, &String::from_str(&env, "alice"));
+
+    let game_id = create_public_game(&env, &client, &player, 0);
+    assert_eq!(client.get_game(&game_id).unwrap().creator, player);
+}
+
+#[test]
+#[should_panic(expected = "Address already registered")]
+fn test_register_player_twice_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _) = setup_with_reward_system(&env);
+
+    let player = Address::generate(&env);
+    client.register_player(&player, &String::from_str(&env, "alice"));
+    client.register_player(&player, &String::from_str(&env, "alice2"));
+}
+
+#[test]
+#[should_panic(expected = "Username must be 3-20 characters")]
+fn test_register_player_short_username_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _) = setup_with_reward_system(&env);
+
+    client.register_player(&Address::generate(&env), &String::from_str(&env, "ab"));
+}
+
+#[test]
+#[should_panic(expected = "Username must be 3-20 characters")]
+fn test_register_player_long_username_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _) = setup_with_reward_system(&env);
+
+    client.register_player(
+        &Address::generate(&env),
+        &String::from_str(&env, "thisusernameiswaytoolong"),
+    );
+}
+
+#[test]
+fn test_register_player_reverts_when_voucher_mint_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    // reward_system is not a contract, so minting the voucher fails
+    client.initialize(&owner, &reward_system, &usdc_token);
+    client.set_registration_voucher_value(&2_0000000);
+
+    let player = Address::generate(&env);
+    let result = client.try_register_player(&player, &String::from_str(&env, "alice"));
+
+    assert!(result.is_err());
+    assert!(!client.is_registered(&player));
+}
+
+// -----------------------------------------------------------------------
+// end_turn / claim_timeout
+// -----------------------------------------------------------------------
+
+/// Starts a staked game of `1 + extra` players with the given turn timeout.
+fn start_timed_game(
+    env: &Env,
+    client: &TycoonMainGameClient<'_>,
+    contract_id: &Address,
+    usdc_token: &Address,
+    extra: u32,
+    turn_timeout: u64,
+) -> (u64, Vec<Address>) {
+    let mut settings = make_settings(env);
+    settings.turn_timeout = turn_timeout;
+    start_game_with_settings(env, client, contract_id, usdc_token, 100, extra, &settings)
+}
+
+/// Marks the current turn as moved directly in storage, so `end_turn` can
+/// be called without rolling and moving.
+fn mark_moved(env: &Env, contract_id: &Address, game_id: u64) {
+    env.as_contract(contract_id, || {
+        let mut state = storage::get_turn_state(env, game_id).unwrap();
+        state.moved = true;
+        storage::set_turn_state(env, game_id, &state);
+    });
+}
+
+#[test]
+fn test_start_game_begins_first_turn() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 2, 60);
+
+    let state = client.get_turn_state(&game_id).unwrap();
+    assert_eq!(state.current, 0);
+    assert_eq!(state.turn_number, 1);
+    assert_eq!(state.turn_started_at, 1_000);
+    for player in turn_order.iter() {
+        assert_eq!(client.get_last_action(&game_id, &player), Some(1_000));
+    }
+    assert!(client.get_eliminated(&game_id).is_empty());
+}
+
+#[test]
+fn test_end_turn_advances_and_wraps() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 60);
+
+    env.ledger().set_timestamp(1_030);
+    mark_moved(&env, &contract_id, game_id);
+    client.end_turn(&game_id, &turn_order.get(0).unwrap());
+
+    let state = client.get_turn_state(&game_id).unwrap();
+    assert_eq!(state.current, 1);
+    assert_eq!(state.turn_number, 2);
+    assert_eq!(state.turn_started_at, 1_030);
+    assert_eq!(
+        client.get_last_action(&game_id, &turn_order.get(0).unwrap()),
+        Some(1_030)
+    );
+
+    env.ledger().set_timestamp(1_050);
+    mark_moved(&env, &contract_id, game_id);
+    client.end_turn(&game_id, &turn_order.get(1).unwrap());
+
+    let state = client.get_turn_state(&game_id).unwrap();
+    assert_eq!(state.current, 0);
+    assert_eq!(state.turn_number, 3);
+}
+
+#[test]
+fn test_end_turn_emits_turn_started() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 60);
+
+    mark_moved(&env, &contract_id, game_id);
+    client.end_turn(&game_id, &turn_order.get(0).unwrap());
+
+    assert!(!env.events().all().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Not your turn")]
+fn test_end_turn_not_your_turn_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 60);
+
+    client.end_turn(&game_id, &turn_order.get(1).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Game is not ongoing")]
+fn test_end_turn_pending_game_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = new_player(&env, &contract_id, &usdc_token, 100);
+    let game_id = create_public_game(&env, &client, &creator, 100);
+
+    client.end_turn(&game_id, &creator);
+}
+
+#[test]
+fn test_claim_timeout_eliminates_idle_player() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 2, 60);
+    let idle = turn_order.get(0).unwrap();
+    let next = turn_order.get(1).unwrap();
+    let claimer = turn_order.get(2).unwrap();
+
+    env.ledger().set_timestamp(1_060);
+    client.claim_timeout(&game_id, &claimer);
+
+    assert_eq!(
+        client.get_turn_order(&game_id).unwrap(),
+        vec![&env, next.clone(), claimer.clone()]
+    );
+    assert_eq!(client.get_eliminated(&game_id), vec![&env, idle]);
+
+    let state = client.get_turn_state(&game_id).unwrap();
+    assert_eq!(state.current, 0);
+    assert_eq!(state.turn_number, 2);
+    assert_eq!(state.turn_started_at, 1_060);
+
+    // The forfeited stake stays in the pot
+    let game = client.get_game(&game_id).unwrap();
+    assert!(matches!(game.status, GameStatus::Ongoing));
+    assert_eq!(game.total_staked, 300);
+}
+
+#[test]
+fn test_claim_timeout_wraps_to_first_player() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 2, 60);
+    mark_moved(&env, &contract_id, game_id);
+    client.end_turn(&game_id, &turn_order.get(0).unwrap());
+    mark_moved(&env, &contract_id, game_id);
+    client.end_turn(&game_id, &turn_order.get(1).unwrap());
+
+    env.ledger().set_timestamp(1_100);
+    client.claim_timeout(&game_id, &turn_order.get(0).unwrap());
+
+    let state = client.get_turn_state(&game_id).unwrap();
+    let remaining = client.get_turn_order(&game_id).unwrap();
+    assert_eq!(
+        remaining.get(state.current).unwrap(),
+        turn_order.get(0).unwrap()
+    );
+}
+
+#[test]
+fn test_claim_timeout_last_player_settles_game() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 60);
+    let idle = turn_order.get(0).unwrap();
+    let claimer = turn_order.get(1).unwrap();
+
+    env.ledger().set_timestamp(1_061);
+    client.claim_timeout(&game_id, &claimer);
+
+    let game = client.get_game(&game_id).unwrap();
+    assert!(matches!(game.status, GameStatus::Ended));
+    assert_eq!(game.winner, Some(claimer.clone()));
+    assert_eq!(
+        client.get_placements(&game_id).unwrap(),
+        vec![&env, claimer.clone(), idle.clone()]
+    );
+
+    let token = TokenClient::new(&env, &usdc_token);
+    assert_eq!(token.balance(&claimer), 200);
+    assert_eq!(token.balance(&idle), 0);
+}
+
+#[test]
+fn test_claim_timeout_unfilled_paid_places_go_to_winner() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let mut settings = make_settings(&env);
+    settings.turn_timeout = 60;
+    settings.payout_bps = vec![&env, 5_000, 3_000, 2_000];
+    let (game_id, turn_order) =
+        start_game_with_settings(&env, &client, &contract_id, &usdc_token, 100, 1, &settings);
+    let idle = turn_order.get(0).unwrap();
+    let claimer = turn_order.get(1).unwrap();
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 60);
+    client.claim_timeout(&game_id, &claimer);
+
+    let token = TokenClient::new(&env, &usdc_token);
+    assert_eq!(token.balance(&claimer), 140);
+    assert_eq!(token.balance(&idle), 60);
+}
+
+#[test]
+#[should_panic(expected = "Turn has not timed out")]
+fn test_claim_timeout_before_deadline_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 60);
+
+    env.ledger().set_timestamp(1_059);
+    client.claim_timeout(&game_id, &turn_order.get(1).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Turn has not timed out")]
+fn test_claim_timeout_disabled_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 0);
+
+    env.ledger().set_timestamp(1_000_000);
+    client.claim_timeout(&game_id, &turn_order.get(1).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Cannot claim timeout against yourself")]
+fn test_claim_timeout_against_self_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 60);
+
+    env.ledger().set_timestamp(1_100);
+    client.claim_timeout(&game_id, &turn_order.get(0).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Claimer is not in this game")]
+fn test_claim_timeout_by_outsider_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (game_id, _) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 60);
+
+    env.ledger().set_timestamp(1_100);
+    client.claim_timeout(&game_id, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Claimer is not in this game")]
+fn test_claim_timeout_by_eliminated_player_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 2, 60);
+
+    env.ledger().set_timestamp(1_100);
+    client.claim_timeout(&game_id, &turn_order.get(2).unwrap());
+
+    env.ledger().set_timestamp(1_200);
+    client.claim_timeout(&game_id, &turn_order.get(0).unwrap());
+}
+
+// -----------------------------------------------------------------------
+// Settlement deadline
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_start_game_sets_settlement_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    assert_eq!(client.get_settlement_timeout(), 7 * 24 * 60 * 60);
+    client.set_settlement_timeout(&3_600);
+
+    let (game_id, _) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 0);
+
+    assert_eq!(client.get_settlement_deadline(&game_id), Some(4_600));
+}
+
+#[test]
+fn test_settlement_timeout_change_does_not_affect_started_game() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    client.set_settlement_timeout(&3_600);
+
+    let (game_id, _) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 0);
+    client.set_settlement_timeout(&100_000);
+
+    assert_eq!(client.get_settlement_deadline(&game_id), Some(4_600));
+}
+
+#[test]
+#[should_panic(expected = "Settlement timeout must be non-zero")]
+fn test_set_settlement_timeout_zero_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    client.set_settlement_timeout(&0);
+}
+
+#[test]
+fn test_claim_settlement_refund_refunds_pro_rata() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    client.set_settlement_timeout(&3_600);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 2, 0);
+
+    env.ledger().set_timestamp(4_600);
+    client.claim_settlement_refund(&game_id, &turn_order.get(1).unwrap());
+
+    let token = TokenClient::new(&env, &usdc_token);
+    for player in turn_order.iter() {
+        assert_eq!(token.balance(&player), 100);
+    }
+    assert_eq!(token.balance(&contract_id), 0);
+
+    let game = client.get_game(&game_id).unwrap();
+    assert!(matches!(game.status, GameStatus::Ended));
+    assert_eq!(game.total_staked, 0);
+    assert_eq!(game.winner, None);
+    assert_eq!(game.ended_at, 4_600);
+}
+
+#[test]
+fn test_claim_settlement_refund_includes_eliminated_players() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    client.set_settlement_timeout(&3_600);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 2, 60);
+    env.ledger().set_timestamp(1_100);
+    client.claim_timeout(&game_id, &turn_order.get(1).unwrap());
+
+    env.ledger().set_timestamp(5_000);
+    client.claim_settlement_refund(&game_id, &turn_order.get(0).unwrap());
+
+    let token = TokenClient::new(&env, &usdc_token);
+    for player in turn_order.iter() {
+        assert_eq!(token.balance(&player), 100);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Settlement deadline has not passed")]
+fn test_claim_settlement_refund_before_deadline_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    client.set_settlement_timeout(&3_600);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 0);
+
+    env.ledger().set_timestamp(4_599);
+    client.claim_settlement_refund(&game_id, &turn_order.get(0).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Player is not in this game")]
+fn test_claim_settlement_refund_by_outsider_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (game_id, _) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 0);
+
+    env.ledger().set_timestamp(1_000_000);
+    client.claim_settlement_refund(&game_id, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Game is not ongoing")]
+fn test_claim_settlement_refund_after_settlement_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 0);
+    client.end_game(&game_id, &owner, &turn_order);
+
+    env.ledger().set_timestamp(1_000_000);
+    client.claim_settlement_refund(&game_id, &turn_order.get(0).unwrap());
+}
+
+// -----------------------------------------------------------------------
+// submit_signed_result
+// -----------------------------------------------------------------------
+
+/// Returns a deterministic result signing key.
+fn result_signing_key() -> SigningKey {
+    SigningKey::from_bytes(&[7u8; 32])
+}
+
+/// Registers `result_signing_key` as the contract's result signer.
+fn register_result_signer(env: &Env, client: &TycoonMainGameClient<'_>) {
+    let public_key = result_signing_key().verifying_key().to_bytes();
+    client.set_result_signer(&BytesN::from_array(env, &public_key));
+}
+
+/// Signs a game result payload for `contract_id` with `key`.
+fn sign_result(
+    env: &Env,
+    key: &SigningKey,
+    contract_id: &Address,
+    game_id: u64,
+    placements: &Vec<Address>,
+    state_hash: &BytesN<32>,
+    nonce: u64,
+) -> BytesN<64> {
+    let payload = GameResultPayload {
+        contract: contract_id.clone(),
+        game_id,
+        placements: placements.clone(),
+        state_hash: state_hash.clone(),
+        nonce,
+    };
+    let message = attestation::message(env, &payload);
+    let mut buf = [0u8; 1024];
+    let len = message.len() as usize;
+    message.copy_into_slice(&mut buf[..len]);
+    BytesN::from_array(env, &key.sign(&buf[..len]).to_bytes())
+}
+
+#[test]
+fn test_submit_signed_result_settles_game() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    register_result_signer(&env, &client);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 0);
+    let state_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let signature = sign_result(
+        &env,
+        &result_signing_key(),
+        &contract_id,
+        game_id,
+        &turn_order,
+        &state_hash,
+        1,
+    );
+
+    // Anyone can relay the signed result, including the winner
+    let relayer = turn_order.get(0).unwrap();
+    client.submit_signed_result(&relayer, &game_id, &turn_order, &state_hash, &1, &signature);
+
+    let game = client.get_game(&game_id).unwrap();
+    assert!(matches!(game.status, GameStatus::Ended));
+    assert_eq!(game.winner, Some(relayer.clone()));
+    assert_eq!(client.get_result_nonce(&game_id), 1);
+    assert_eq!(client.get_result_state_hash(&game_id), Some(state_hash));
+    assert_eq!(TokenClient::new(&env, &usdc_token).balance(&relayer), 200);
+}
+
+#[test]
+#[should_panic]
+fn test_submit_signed_result_wrong_key_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    register_result_signer(&env, &client);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 0);
+    let state_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let signature = sign_result(
+        &env,
+        &SigningKey::from_bytes(&[9u8; 32]),
+        &contract_id,
+        game_id,
+        &turn_order,
+        &state_hash,
+        1,
+    );
+
+    client.submit_signed_result(&owner, &game_id, &turn_order, &state_hash, &1, &signature);
+}
+
+#[test]
+#[should_panic]
+fn test_submit_signed_result_tampered_placements_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    register_result_signer(&env, &client);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 0);
+    let state_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let signature = sign_result(
+        &env,
+        &result_signing_key(),
+        &contract_id,
+        game_id,
+        &turn_order,
+        &state_hash,
+        1,
+    );
+
+    let swapped = vec![&env, turn_order.get(1).unwrap(), turn_order.get(0).unwrap()];
+    client.submit_signed_result(&owner, &game_id, &swapped, &state_hash, &1, &signature);
+}
+
+#[test]
+#[should_panic]
+fn test_submit_signed_result_other_contract_signature_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    register_result_signer(&env, &client);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 0);
+    let state_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let signature = sign_result(
+        &env,
+        &result_signing_key(),
+        &Address::generate(&env),
+        game_id,
+        &turn_order,
+        &state_hash,
+        1,
+    );
+
+    client.submit_signed_result(&owner, &game_id, &turn_order, &state_hash, &1, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Result signer not set")]
+fn test_submit_signed_result_without_signer_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 0);
+    let state_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let signature = sign_result(
+        &env,
+        &result_signing_key(),
+        &contract_id,
+        game_id,
+        &turn_order,
+        &state_hash,
+        1,
+    );
+
+    client.submit_signed_result(&owner, &game_id, &turn_order, &state_hash, &1, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Nonce already used")]
+fn test_submit_signed_result_zero_nonce_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    register_result_signer(&env, &client);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 0);
+    let state_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let signature = sign_result(
+        &env,
+        &result_signing_key(),
+        &contract_id,
+        game_id,
+        &turn_order,
+        &state_hash,
+        0,
+    );
+
+    client.submit_signed_result(&owner, &game_id, &turn_order, &state_hash, &0, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Game is not ongoing")]
+fn test_submit_signed_result_replay_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    register_result_signer(&env, &client);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 0);
+    let state_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let signature = sign_result(
+        &env,
+        &result_signing_key(),
+        &contract_id,
+        game_id,
+        &turn_order,
+        &state_hash,
+        1,
+    );
+
+    client.submit_signed_result(&owner, &game_id, &turn_order, &state_hash, &1, &signature);
+    client.submit_signed_result(&owner, &game_id, &turn_order, &state_hash, &1, &signature);
+}
+
+// -----------------------------------------------------------------------
+// Dispute window
+// -----------------------------------------------------------------------
+
+/// Starts a two-player game staked at 100 with a 1-hour dispute window and
+/// a bond of 50, then reports `turn_order` as the result at t=1_000.
+/// Returns (contract_id, client, owner, usdc_token, game_id, turn_order).
+fn report_disputable_result(
+    env: &Env,
+) -> (
+    Address,
+    TycoonMainGameClient<'_>,
+    Address,
+    Address,
+    u64,
+    Vec<Address>,
+) {
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    client.set_dispute_config(&3_600, &50);
+
+    let (game_id, turn_order) = start_timed_game(env, &client, &contract_id, &usdc_token, 1, 0);
+    client.end_game(&game_id, &owner, &turn_order);
+
+    (contract_id, client, owner, usdc_token, game_id, turn_order)
+}
+
+#[test]
+fn test_end_game_with_dispute_window_reports_result() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, usdc_token, game_id, turn_order) = report_disputable_result(&env);
+
+    let game = client.get_game(&game_id).unwrap();
+    assert!(matches!(game.status, GameStatus::Reported));
+    assert_eq!(game.winner, None);
+
+    let pending = client.get_pending_result(&game_id).unwrap();
+    assert_eq!(pending.placements, turn_order);
+    assert_eq!(pending.reported_at, 1_000);
+    assert_eq!(pending.finalizes_at, 4_600);
+    assert_eq!(pending.bond, 50);
+    assert_eq!(pending.disputed_by, None);
+
+    // Nothing is paid out yet
+    assert_eq!(
+        TokenClient::new(&env, &usdc_token).balance(&contract_id),
+        200
+    );
+}
+
+#[test]
+fn test_finalize_result_after_window_settles() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, usdc_token, game_id, turn_order) = report_disputable_result(&env);
+
+    env.ledger().set_timestamp(4_600);
+    client.finalize_result(&game_id);
+
+    let game = client.get_game(&game_id).unwrap();
+    assert!(matches!(game.status, GameStatus::Ended));
+    assert_eq!(game.winner, Some(turn_order.get(0).unwrap()));
+    assert_eq!(client.get_pending_result(&game_id), None);
+    assert_eq!(
+        TokenClient::new(&env, &usdc_token).balance(&turn_order.get(0).unwrap()),
+        200
+    );
+}
+
+#[test]
+#[should_panic(expected = "Dispute window is still open")]
+fn test_finalize_result_during_window_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, _, game_id, _) = report_disputable_result(&env);
+
+    env.ledger().set_timestamp(4_599);
+    client.finalize_result(&game_id);
+}
+
+#[test]
+#[should_panic(expected = "Game is not ongoing")]
+fn test_reported_game_cannot_be_reported_again() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, owner, _, game_id, turn_order) = report_disputable_result(&env);
+
+    client.end_game(&game_id, &owner, &turn_order);
+}
+
+#[test]
+fn test_dispute_result_posts_bond() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, usdc_token, game_id, turn_order) = report_disputable_result(&env);
+    let disputer = turn_order.get(1).unwrap();
+    StellarAssetClient::new(&env, &usdc_token).mint(&disputer, &50);
+
+    client.dispute_result(&game_id, &disputer);
+
+    let pending = client.get_pending_result(&game_id).unwrap();
+    assert_eq!(pending.disputed_by, Some(disputer.clone()));
+    let token = TokenClient::new(&env, &usdc_token);
+    assert_eq!(token.balance(&disputer), 0);
+    assert_eq!(token.balance(&contract_id), 250);
+}
+
+#[test]
+#[should_panic(expected = "Result is disputed")]
+fn test_finalize_disputed_result_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, usdc_token, game_id, turn_order) = report_disputable_result(&env);
+    let disputer = turn_order.get(1).unwrap();
+    StellarAssetClient::new(&env, &usdc_token).mint(&disputer, &50);
+    client.dispute_result(&game_id, &disputer);
+
+    env.ledger().set_timestamp(10_000);
+    client.finalize_result(&game_id);
+}
+
+#[test]
+#[should_panic(expected = "Dispute window has closed")]
+fn test_dispute_after_window_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, _, game_id, turn_order) = report_disputable_result(&env);
+
+    env.ledger().set_timestamp(4_600);
+    client.dispute_result(&game_id, &turn_order.get(1).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Result already disputed")]
+fn test_dispute_twice_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, usdc_token, game_id, turn_order) = report_disputable_result(&env);
+    let asset = StellarAssetClient::new(&env, &usdc_token);
+    asset.mint(&turn_order.get(0).unwrap(), &50);
+    asset.mint(&turn_order.get(1).unwrap(), &50);
+
+    client.dispute_result(&game_id, &turn_order.get(1).unwrap());
+    client.dispute_result(&game_id, &turn_order.get(0).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Player is not in this game")]
+fn test_dispute_by_outsider_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, _, game_id, _) = report_disputable_result(&env);
+
+    client.dispute_result(&game_id, &Address::generate(&env));
+}
+
+#[test]
+fn test_resolve_dispute_upheld_forfeits_bond() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, owner, usdc_token, game_id, turn_order) = report_disputable_result(&env);
+    let winner = turn_order.get(0).unwrap();
+    let disputer = turn_order.get(1).unwrap();
+    StellarAssetClient::new(&env, &usdc_token).mint(&disputer, &50);
+    client.dispute_result(&game_id, &disputer);
+
+    client.resolve_dispute(&game_id, &owner, &None);
+
+    let game = client.get_game(&game_id).unwrap();
+    assert!(matches!(game.status, GameStatus::Ended));
+    assert_eq!(game.winner, Some(winner.clone()));
+    let token = TokenClient::new(&env, &usdc_token);
+    assert_eq!(token.balance(&winner), 200);
+    assert_eq!(token.balance(&disputer), 0);
+    assert_eq!(client.get_treasury_balance(&usdc_token), 50);
+}
+
+#[test]
+fn test_resolve_dispute_override_by_arbiter_refunds_bond() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, usdc_token, game_id, turn_order) = report_disputable_result(&env);
+    let arbiter = Address::generate(&env);
+    client.set_arbiter(&arbiter);
+    let disputer = turn_order.get(1).unwrap();
+    StellarAssetClient::new(&env, &usdc_token).mint(&disputer, &50);
+    client.dispute_result(&game_id, &disputer);
+
+    let corrected = vec![&env, disputer.clone(), turn_order.get(0).unwrap()];
+    client.resolve_dispute(&game_id, &arbiter, &Some(corrected.clone()));
+
+    let game = client.get_game(&game_id).unwrap();
+    assert_eq!(game.winner, Some(disputer.clone()));
+    assert_eq!(client.get_placements(&game_id), Some(corrected));
+    let token = TokenClient::new(&env, &usdc_token);
+    assert_eq!(token.balance(&disputer), 250);
+    assert_eq!(client.get_treasury_balance(&usdc_token), 0);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller must be owner or arbiter")]
+fn test_resolve_dispute_unauthorized_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, usdc_token, game_id, turn_order) = report_disputable_result(&env);
+    let disputer = turn_order.get(1).unwrap();
+    StellarAssetClient::new(&env, &usdc_token).mint(&disputer, &50);
+    client.dispute_result(&game_id, &disputer);
+
+    client.resolve_dispute(&game_id, &disputer, &None);
+}
+
+#[test]
+#[should_panic(expected = "Result is not disputed")]
+fn test_resolve_undisputed_result_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, owner, _, game_id, _) = report_disputable_result(&env);
+
+    client.resolve_dispute(&game_id, &owner, &None);
+}
+
+#[test]
+#[should_panic(expected = "Game result is not reported")]
+fn test_dispute_without_reported_result_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 1, 0);
+    client.dispute_result(&game_id, &turn_order.get(0).unwrap());
+}
+
+#[test]
+fn test_settlement_refund_returns_dispute_bond() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, usdc_token, game_id, turn_order) = report_disputable_result(&env);
+    let disputer = turn_order.get(1).unwrap();
+    StellarAssetClient::new(&env, &usdc_token).mint(&disputer, &50);
+    client.dispute_result(&game_id, &disputer);
+
+    // Nobody resolves the dispute before the settlement deadline
+    env.ledger().set_timestamp(1_000 + 7 * 24 * 60 * 60);
+    client.claim_settlement_refund(&game_id, &disputer);
+
+    let token = TokenClient::new(&env, &usdc_token);
+    assert_eq!(token.balance(&disputer), 150);
+    assert_eq!(token.balance(&turn_order.get(0).unwrap()), 100);
+    assert_eq!(token.balance(&contract_id), 0);
+    assert_eq!(client.get_pending_result(&game_id), None);
+}
+
+#[test]
+#[should_panic(expected = "Undisputed result must be finalized")]
+fn test_settlement_refund_of_undisputed_result_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, _, game_id, turn_order) = report_disputable_result(&env);
+
+    env.ledger().set_timestamp(1_000 + 7 * 24 * 60 * 60);
+    client.claim_settlement_refund(&game_id, &turn_order.get(1).unwrap());
+}
+
+// -----------------------------------------------------------------------
+// Commit-reveal dice
+// -----------------------------------------------------------------------
+
+/// Starts a two-player game with a backend controller set.
+/// Returns (client, controller, game_id, current_player).
+fn start_dice_game(env: &Env) -> (TycoonMainGameClient<'_>, Address, u64, Address) {
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    let controller = Address::generate(env);
+    client.set_backend_game_controller(&controller);
+
+    let (game_id, turn_order) = start_timed_game(env, &client, &contract_id, &usdc_token, 1, 0);
+    (client, controller, game_id, turn_order.get(0).unwrap())
+}
+
+fn dice_commitment(env: &Env, seed: &BytesN<32>) -> BytesN<32> {
+    env.crypto().sha256(&seed.clone().into()).into()
+}
+
+#[test]
+fn test_dice_commit_reveal_rolls_deterministically() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, controller, game_id, player) = start_dice_game(&env);
+
+    let player_seed = BytesN::from_array(&env, &[1u8; 32]);
+    let backend_seed = BytesN::from_array(&env, &[2u8; 32]);
+    client.commit_dice(&game_id, &player, &dice_commitment(&env, &player_seed));
+    client.commit_dice(&game_id, &controller, &dice_commitment(&env, &backend_seed));
+
+    let round = client.get_dice_round(&game_id, &1).unwrap();
+    assert_eq!(round.reveal_deadline, 1_300);
+    assert_eq!(round.die1, 0);
+
+    client.reveal_dice(&game_id, &controller, &backend_seed);
+    client.reveal_dice(&game_id, &player, &player_seed);
+
+    let round = client.get_dice_round(&game_id, &1).unwrap();
+    let (die1, die2) = dice::derive_roll(&env, &player_seed, &backend_seed);
+    assert_eq!((round.die1, round.die2), (die1, die2));
+    assert!((1..=6).contains(&round.die1));
+    assert!((1..=6).contains(&round.die2));
+    assert!(!round.fallback);
+}
+
+#[test]
+fn test_dice_rounds_are_per_turn() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, controller, game_id, player) = start_dice_game(&env);
+
+    roll_and_move(&env, &client, &controller, game_id, &player, 7);
+    client.end_turn(&game_id, &player);
+
+    let seed = BytesN::from_array(&env, &[1u8; 32]);
+    assert!(client.get_dice_round(&game_id, &2).is_none());
+    client.commit_dice(&game_id, &controller, &dice_commitment(&env, &seed));
+    assert!(client.get_dice_round(&game_id, &2).is_some());
+}
+
+#[test]
+#[should_panic(expected = "Seed does not match commitment")]
+fn test_reveal_wrong_seed_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, controller, game_id, player) = start_dice_game(&env);
+
+    let seed = BytesN::from_array(&env, &[1u8; 32]);
+    client.commit_dice(&game_id, &player, &dice_commitment(&env, &seed));
+    client.commit_dice(&game_id, &controller, &dice_commitment(&env, &seed));
+    client.reveal_dice(&game_id, &controller, &seed);
+
+    client.reveal_dice(&game_id, &player, &BytesN::from_array(&env, &[3u8; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Dice not committed")]
+fn test_reveal_before_both_commit_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, game_id, player) = start_dice_game(&env);
+
+    let seed = BytesN::from_array(&env, &[1u8; 32]);
+    client.commit_dice(&game_id, &player, &dice_commitment(&env, &seed));
+    client.reveal_dice(&game_id, &player, &seed);
+}
+
+#[test]
+#[should_panic(expected = "Dice already committed")]
+fn test_commit_dice_twice_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, game_id, player) = start_dice_game(&env);
+
+    let seed = BytesN::from_array(&env, &[1u8; 32]);
+    client.commit_dice(&game_id, &player, &dice_commitment(&env, &seed));
+    client.commit_dice(&game_id, &player, &dice_commitment(&env, &seed));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller must be current player or backend game controller")]
+fn test_commit_dice_out_of_turn_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, game_id, _) = start_dice_game(&env);
+
+    let seed = BytesN::from_array(&env, &[1u8; 32]);
+    client.commit_dice(
+        &game_id,
+        &Address::generate(&env),
+        &dice_commitment(&env, &seed),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Dice round has expired")]
+fn test_reveal_after_deadline_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, controller, game_id, player) = start_dice_game(&env);
+
+    let seed = BytesN::from_array(&env, &[1u8; 32]);
+    client.commit_dice(&game_id, &player, &dice_commitment(&env, &seed));
+    client.commit_dice(&game_id, &controller, &dice_commitment(&env, &seed));
+
+    env.ledger().set_timestamp(1_300);
+    client.reveal_dice(&game_id, &player, &seed);
+}
+
+#[test]
+fn test_resolve_dice_timeout_uses_prng_when_nobody_revealed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, controller, game_id, player) = start_dice_game(&env);
+
+    let seed = BytesN::from_array(&env, &[1u8; 32]);
+    client.commit_dice(&game_id, &player, &dice_commitment(&env, &seed));
+    client.commit_dice(&game_id, &controller, &dice_commitment(&env, &seed));
+
+    env.ledger().set_timestamp(1_300);
+    client.resolve_dice_timeout(&game_id);
+
+    let round = client.get_dice_round(&game_id, &1).unwrap();
+    assert!(round.fallback);
+    assert!(!round.forfeited);
+    assert!((1..=6).contains(&round.die1));
+    assert!((1..=6).contains(&round.die2));
+}
+
+#[test]
+#[should_panic(expected = "Backend must reveal first")]
+fn test_player_reveal_before_backend_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, controller, game_id, player) = start_dice_game(&env);
+
+    let player_seed = BytesN::from_array(&env, &[1u8; 32]);
+    let backend_seed = BytesN::from_array(&env, &[2u8; 32]);
+    client.commit_dice(&game_id, &player, &dice_commitment(&env, &player_seed));
+    client.commit_dice(&game_id, &controller, &dice_commitment(&env, &backend_seed));
+
+    client.reveal_dice(&game_id, &player, &player_seed);
+}
+
+#[test]
+fn test_player_reveal_after_backend_rolls() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, controller, game_id, player) = start_dice_game(&env);
+
+    let player_seed = BytesN::from_array(&env, &[1u8; 32]);
+    let backend_seed = BytesN::from_array(&env, &[2u8; 32]);
+    client.commit_dice(&game_id, &player, &dice_commitment(&env, &player_seed));
+    client.commit_dice(&game_id, &controller, &dice_commitment(&env, &backend_seed));
+
+    client.reveal_dice(&game_id, &controller, &backend_seed);
+    assert_eq!(client.get_dice_round(&game_id, &1).unwrap().die1, 0);
+
+    client.reveal_dice(&game_id, &player, &player_seed);
+    let round = client.get_dice_round(&game_id, &1).unwrap();
+    let (die1, die2) = dice::derive_roll(&env, &player_seed, &backend_seed);
+    assert_eq!((round.die1, round.die2), (die1, die2));
+    assert!(!round.fallback);
+}
+
+#[test]
+fn test_backend_withholding_uses_prng() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, controller, game_id, player) = start_dice_game(&env);
+
+    let player_seed = BytesN::from_array(&env, &[1u8; 32]);
+    let backend_seed = BytesN::from_array(&env, &[2u8; 32]);
+    client.commit_dice(&game_id, &player, &dice_commitment(&env, &player_seed));
+    client.commit_dice(&game_id, &controller, &dice_commitment(&env, &backend_seed));
+
+    // The backend never reveals, so the player cannot reveal either
+    env.ledger().set_timestamp(1_300);
+    client.resolve_dice_timeout(&game_id);
+
+    let round = client.get_dice_round(&game_id, &1).unwrap();
+    assert!(round.fallback);
+    assert!(!round.forfeited);
+    assert_eq!(round.player_seed, None);
+    assert!((1..=6).contains(&round.die1));
+    assert!((1..=6).contains(&round.die2));
+}
+
+#[test]
+fn test_player_withholding_reveal_forfeits_move() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, controller, game_id, player) = start_dice_game(&env);
+
+    let player_seed = BytesN::from_array(&env, &[1u8; 32]);
+    let backend_seed = BytesN::from_array(&env, &[2u8; 32]);
+    client.commit_dice(&game_id, &player, &dice_commitment(&env, &player_seed));
+    client.commit_dice(&game_id, &controller, &dice_commitment(&env, &backend_seed));
+    client.reveal_dice(&game_id, &controller, &backend_seed);
+
+    // The player can now compute the roll and withholds their reveal
+    env.ledger().set_timestamp(1_300);
+    client.resolve_dice_timeout(&game_id);
+
+    let round = client.get_dice_round(&game_id, &1).unwrap();
+    assert!(round.forfeited);
+    assert_eq!((round.die1, round.die2), (0, 0));
+    assert!(client.get_turn_state(&game_id).unwrap().moved);
+    assert_eq!(client.get_position(&game_id, &player), 0);
+
+    client.end_turn(&game_id, &player);
+    assert_eq!(client.get_turn_state(&game_id).unwrap().turn_number, 2);
+}
+
+#[test]
+#[should_panic(expected = "Already moved this turn")]
+fn test_move_after_forfeited_roll_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, controller, game_id, player) = start_dice_game(&env);
+
+    let seed = BytesN::from_array(&env, &[1u8; 32]);
+    client.commit_dice(&game_id, &player, &dice_commitment(&env, &seed));
+    client.commit_dice(&game_id, &controller, &dice_commitment(&env, &seed));
+    client.reveal_dice(&game_id, &controller, &seed);
+
+    env.ledger().set_timestamp(1_300);
+    client.resolve_dice_timeout(&game_id);
+    client.move_token(&game_id, &player);
+}
+
+#[test]
+#[should_panic(expected = "Reveal deadline has not passed")]
+fn test_resolve_dice_timeout_before_deadline_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, game_id, player) = start_dice_game(&env);
+
+    let seed = BytesN::from_array(&env, &[1u8; 32]);
+    client.commit_dice(&game_id, &player, &dice_commitment(&env, &seed));
+
+    env.ledger().set_timestamp(1_299);
+    client.resolve_dice_timeout(&game_id);
+}
+
+#[test]
+#[should_panic(expected = "Dice already rolled")]
+fn test_resolve_dice_timeout_after_roll_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, controller, game_id, player) = start_dice_game(&env);
+
+    let seed = BytesN::from_array(&env, &[1u8; 32]);
+    client.commit_dice(&game_id, &player, &dice_commitment(&env, &seed));
+    client.commit_dice(&game_id, &controller, &dice_commitment(&env, &seed));
+    client.reveal_dice(&game_id, &controller, &seed);
+    client.reveal_dice(&game_id, &player, &seed);
+
+    env.ledger().set_timestamp(1_300);
+    client.resolve_dice_timeout(&game_id);
+}
+
+// -----------------------------------------------------------------------
+// Board and property ledger
+// -----------------------------------------------------------------------
+
+/// Finds player and backend seeds whose derived roll satisfies `accept`.
+fn seeds_for_roll(env: &Env, accept: impl Fn(u32, u32) -> bool) -> (BytesN<32>, BytesN<32>) {
+    let backend_seed = BytesN::from_array(env, &[0u8; 32]);
+    for a in 0..=255u8 {
+        for b in 0..=255u8 {
+            let mut bytes = [a; 32];
+            bytes[31] = b;
+            let player_seed = BytesN::from_array(env, &bytes);
+            let (die1, die2) = dice::derive_roll(env, &player_seed, &backend_seed);
+            if accept(die1, die2) {
+                return (player_seed, backend_seed);
+            }
+        }
+    }
+    panic!("no seeds found for roll");
+}
+
+/// Rolls the dice for the current turn so they satisfy `accept`.
+fn roll_dice_where(
+    env: &Env,
+    client: &TycoonMainGameClient<'_>,
+    controller: &Address,
+    game_id: u64,
+    player: &Address,
+    accept: impl Fn(u32, u32) -> bool,
+) {
+    let (player_seed, backend_seed) = seeds_for_roll(env, accept);
+    client.commit_dice(&game_id, player, &dice_commitment(env, &player_seed));
+    client.commit_dice(&game_id, controller, &dice_commitment(env, &backend_seed));
+    client.reveal_dice(&game_id, controller, &backend_seed);
+    client.reveal_dice(&game_id, player, &player_seed);
+}
+
+/// Rolls a non-double `total` for the current player and moves their token.
+fn roll_and_move(
+    env: &Env,
+    client: &TycoonMainGameClient<'_>,
+    controller: &Address,
+    game_id: u64,
+    player: &Address,
+    total: u32,
+) {
+    roll_dice_where(env, client, controller, game_id, player, |d1, d2| {
+        d1 + d2 == total && d1 != d2
+    });
+    client.move_token(&game_id, player);
+}
+
+/// Starts a two-player board game with 1500 starting cash.
+/// Returns (contract_id, client, controller, game_id, turn_order).
+fn start_board_game(
+    env: &Env,
+) -> (
+    Address,
+    TycoonMainGameClient<'_>,
+    Address,
+    u64,
+    Vec<Address>,
+) {
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    let controller = Address::generate(env);
+    client.set_backend_game_controller(&controller);
+
+    let (game_id, turn_order) = start_timed_game(env, &client, &contract_id, &usdc_token, 1, 0);
+    (contract_id, client, controller, game_id, turn_order)
+}
+
+/// Assigns `squares` to `owner` directly in storage.
+fn give_properties(
+    env: &Env,
+    contract_id: &Address,
+    game_id: u64,
+    owner: &Address,
+    squares: &[u32],
+) {
+    env.as_contract(contract_id, || {
+        let mut owned = storage::get_player_properties(env, game_id, owner);
+        for &square in squares {
+            storage::set_property_owner(env, game_id, square, owner);
+            owned.push_back(square);
+        }
+        storage::set_player_properties(env, game_id, owner, &owned);
+    });
+}
+
+#[test]
+fn test_start_game_seeds_cash_ledger() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, game_id, turn_order) = start_board_game(&env);
+
+    for player in turn_order.iter() {
+        assert_eq!(client.get_cash(&game_id, &player), 1500);
+        assert_eq!(client.get_position(&game_id, &player), 0);
+        assert!(client.get_properties(&game_id, &player).is_empty());
+    }
+}
+
+#[test]
+fn test_move_and_buy_property() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, controller, game_id, turn_order) = start_board_game(&env);
+    let player = turn_order.get(0).unwrap();
+
+    roll_and_move(&env, &client, &controller, game_id, &player, 5);
+    assert_eq!(client.get_position(&game_id, &player), 5);
+
+    client.buy_property(&game_id, &player);
+
+    assert_eq!(client.get_cash(&game_id, &player), 1300);
+    assert_eq!(
+        client.get_property_owner(&game_id, &5),
+        Some(player.clone())
+    );
+    assert_eq!(client.get_properties(&game_id, &player), vec![&env, 5]);
+    assert_eq!(client.get_net_worth(&game_id, &player), 1500);
+}
+
+#[test]
+fn test_landing_on_owned_property_pays_rent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, controller, game_id, turn_order) = start_board_game(&env);
+    let owner = turn_order.get(0).unwrap();
+    let payer = turn_order.get(1).unwrap();
+
+    roll_and_move(&env, &client, &controller, game_id, &owner, 5);
+    client.buy_property(&game_id, &owner);
+    client.end_turn(&game_id, &owner);
+
+    roll_and_move(&env, &client, &controller, game_id, &payer, 5);
+
+    assert_eq!(client.get_cash(&game_id, &payer), 1475);
+    assert_eq!(client.get_cash(&game_id, &owner), 1325);
+    assert_eq!(client.get_standings(&game_id), vec![&env, owner, payer]);
+}
+
+#[test]
+fn test_full_color_set_doubles_rent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, controller, game_id, turn_order) = start_board_game(&env);
+    let owner = turn_order.get(1).unwrap();
+    let payer = turn_order.get(0).unwrap();
+    give_properties(&env, &contract_id, game_id, &owner, &[1, 3]);
+
+    roll_and_move(&env, &client, &controller, game_id, &payer, 3);
+
+    assert_eq!(client.get_cash(&game_id, &payer), 1492);
+    assert_eq!(client.get_cash(&game_id, &owner), 1508);
+}
+
+#[test]
+fn test_railroad_rent_scales_with_railroads_owned() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, controller, game_id, turn_order) = start_board_game(&env);
+    let owner = turn_order.get(1).unwrap();
+    let payer = turn_order.get(0).unwrap();
+    give_properties(&env, &contract_id, game_id, &owner, &[5, 15, 25]);
+
+    roll_and_move(&env, &client, &controller, game_id, &payer, 5);
+
+    assert_eq!(client.get_cash(&game_id, &payer), 1400);
+}
+
+#[test]
+fn test_utility_rent_uses_dice_total() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, controller, game_id, turn_order) = start_board_game(&env);
+    let owner = turn_order.get(1).unwrap();
+    let payer = turn_order.get(0).unwrap();
+    give_properties(&env, &contract_id, game_id, &owner, &[12]);
+
+    roll_dice_where(&env, &client, &controller, game_id, &payer, |d1, d2| {
+        d1 + d2 == 12
+    });
+    client.move_token(&game_id, &payer);
+
+    assert_eq!(client.get_cash(&game_id, &payer), 1500 - 48);
+}
+
+#[test]
+fn test_landing_on_tax_pays_bank() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, controller, game_id, turn_order) = start_board_game(&env);
+    let player = turn_order.get(0).unwrap();
+
+    roll_and_move(&env, &client, &controller, game_id, &player, 4);
+
+    assert_eq!(client.get_cash(&game_id, &player), 1300);
+}
+
+#[test]
+fn test_passing_go_collects_salary() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, controller, game_id, turn_order) = start_board_game(&env);
+
+    // 9, 18, 27, 36, then 45 wraps to 5 having passed Go
+    for _ in 0..5 {
+        for player in turn_order.iter() {
+            roll_and_move(&env, &client, &controller, game_id, &player, 9);
+            client.end_turn(&game_id, &player);
+        }
+    }
+
+    for player in turn_order.iter() {
+        assert_eq!(client.get_position(&game_id, &player), 5);
+        assert_eq!(client.get_cash(&game_id, &player), 1700);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Dice not rolled")]
+fn test_move_without_dice_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, game_id, turn_order) = start_board_game(&env);
+
+    client.move_token(&game_id, &turn_order.get(0).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Already moved this turn")]
+fn test_move_twice_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, controller, game_id, turn_order) = start_board_game(&env);
+    let player = turn_order.get(0).unwrap();
+
+    roll_and_move(&env, &client, &controller, game_id, &player, 5);
+    client.move_token(&game_id, &player);
+}
+
+#[test]
+#[should_panic(expected = "Player has not moved this turn")]
+fn test_buy_before_moving_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, game_id, turn_order) = start_board_game(&env);
+
+    client.buy_property(&game_id, &turn_order.get(0).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Square is not a property")]
+fn test_buy_non_property_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, controller, game_id, turn_order) = start_board_game(&env);
+    let player = turn_order.get(0).unwrap();
+
+    roll_and_move(&env, &client, &controller, game_id, &player, 7);
+    client.buy_property(&game_id, &player);
+}
+
+#[test]
+#[should_panic(expected = "Property already owned")]
+fn test_buy_owned_property_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, controller, game_id, turn_order) = start_board_game(&env);
+    let player = turn_order.get(0).unwrap();
+    give_properties(&env, &contract_id, game_id, &player, &[5]);
+
+    roll_and_move(&env, &client, &controller, game_id, &player, 5);
+    client.buy_property(&game_id, &player);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient cash")]
+fn test_buy_without_enough_cash_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, controller, game_id, turn_order) = start_board_game(&env);
+    let player = turn_order.get(0).unwrap();
+    env.as_contract(&contract_id, || {
+        storage::set_cash(&env, game_id, &player, 100)
+    });
+
+    roll_and_move(&env, &client, &controller, game_id, &player, 5);
+    client.buy_property(&game_id, &player);
+}
+
+#[test]
+#[should_panic(expected = "Must move before ending turn")]
+fn test_end_turn_before_moving_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, game_id, turn_order) = start_board_game(&env);
+
+    client.end_turn(&game_id, &turn_order.get(0).unwrap());
+}
+
+// -----------------------------------------------------------------------
+// Property auctions
+// -----------------------------------------------------------------------
+
+/// Starts a three-player board game with auction mode on and a 60s turn
+/// timeout; the first player
+/// moves onto Reading Railroad (square 5) and declines it at t=1_000.
+/// Returns (client, game_id, turn_order).
+fn open_railroad_auction(env: &Env) -> (TycoonMainGameClient<'_>, u64, Vec<Address>) {
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    let controller = Address::generate(env);
+    client.set_backend_game_controller(&controller);
+
+    let mut settings = make_settings(env);
+    settings.auction = true;
+    settings.turn_timeout = 60;
+    let (game_id, turn_order) =
+        start_game_with_settings(env, &client, &contract_id, &usdc_token, 100, 2, &settings);
+
+    let player = turn_order.get(0).unwrap();
+    roll_and_move(env, &client, &controller, game_id, &player, 5);
+    client.decline_property(&game_id, &player);
+
+    (client, game_id, turn_order)
+}
+
+#[test]
+fn test_decline_property_opens_auction() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, game_id, turn_order) = open_railroad_auction(&env);
+
+    let auction = client.get_auction(&game_id, &5).unwrap();
+    assert_eq!(auction.excluded, Some(turn_order.get(0).unwrap()));
+    assert_eq!(auction.highest_bidder, None);
+    assert_eq!(auction.ends_at, 1_300);
+}
+
+#[test]
+fn test_auction_awards_property_to_highest_bidder() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, game_id, turn_order) = open_railroad_auction(&env);
+    let bidder1 = turn_order.get(1).unwrap();
+    let bidder2 = turn_order.get(2).unwrap();
+
+    client.place_bid(&game_id, &5, &bidder1, &50);
+    client.place_bid(&game_id, &5, &bidder2, &80);
+    client.place_bid(&game_id, &5, &bidder1, &120);
+
+    // Only the leading bid stays escrowed
+    assert_eq!(client.get_cash(&game_id, &bidder1), 1380);
+    assert_eq!(client.get_cash(&game_id, &bidder2), 1500);
+
+    env.ledger().set_timestamp(1_300);
+    client.settle_auction(&game_id, &5);
+
+    assert_eq!(
+        client.get_property_owner(&game_id, &5),
+        Some(bidder1.clone())
+    );
+    assert_eq!(client.get_properties(&game_id, &bidder1), vec![&env, 5]);
+    assert_eq!(client.get_cash(&game_id, &bidder1), 1380);
+    assert_eq!(client.get_auction(&game_id, &5), None);
+}
+
+#[test]
+fn test_auction_without_bids_leaves_property_with_bank() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, game_id, _) = open_railroad_auction(&env);
+
+    env.ledger().set_timestamp(1_300);
+    client.settle_auction(&game_id, &5);
+
+    assert_eq!(client.get_property_owner(&game_id, &5), None);
+    assert_eq!(client.get_auction(&game_id, &5), None);
+}
+
+#[test]
+#[should_panic(expected = "Auction mode is disabled")]
+fn test_decline_property_without_auction_mode_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, controller, game_id, turn_order) = start_board_game(&env);
+    let player = turn_order.get(0).unwrap();
+
+    roll_and_move(&env, &client, &controller, game_id, &player, 5);
+    client.decline_property(&game_id, &player);
+}
+
+#[test]
+#[should_panic(expected = "Property is being auctioned")]
+fn test_buy_after_declining_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, game_id, turn_order) = open_railroad_auction(&env);
+
+    client.buy_property(&game_id, &turn_order.get(0).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Bidder is excluded from this auction")]
+fn test_decliner_cannot_bid() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, game_id, turn_order) = open_railroad_auction(&env);
+
+    client.place_bid(&game_id, &5, &turn_order.get(0).unwrap(), &10);
+}
+
+#[test]
+#[should_panic(expected = "Bid too low")]
+fn test_bid_must_beat_leader() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, game_id, turn_order) = open_railroad_auction(&env);
+
+    client.place_bid(&game_id, &5, &turn_order.get(1).unwrap(), &50);
+    client.place_bid(&game_id, &5, &turn_order.get(2).unwrap(), &50);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient cash")]
+fn test_bid_above_cash_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, game_id, turn_order) = open_railroad_auction(&env);
+
+    client.place_bid(&game_id, &5, &turn_order.get(1).unwrap(), &1_501);
+}
+
+#[test]
+#[should_panic(expected = "Bidder is not in this game")]
+fn test_outsider_cannot_bid() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, game_id, _) = open_railroad_auction(&env);
+
+    client.place_bid(&game_id, &5, &Address::generate(&env), &10);
+}
+
+#[test]
+#[should_panic(expected = "Auction has ended")]
+fn test_bid_after_end_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, game_id, turn_order) = open_railroad_auction(&env);
+
+    env.ledger().set_timestamp(1_300);
+    client.place_bid(&game_id, &5, &turn_order.get(1).unwrap(), &10);
+}
+
+#[test]
+#[should_panic(expected = "Auction is still open")]
+fn test_settle_auction_early_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, game_id, _) = open_railroad_auction(&env);
+
+    env.ledger().set_timestamp(1_299);
+    client.settle_auction(&game_id, &5);
+}
+
+// -----------------------------------------------------------------------
+// Trades
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_accept_trade_swaps_properties_and_cash() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let alice = turn_order.get(0).unwrap();
+    let bob = turn_order.get(1).unwrap();
+    give_properties(&env, &contract_id, game_id, &alice, &[1, 3]);
+    give_properties(&env, &contract_id, game_id, &bob, &[5]);
+
+    let trade_id = client.propose_trade(
+        &game_id,
+        &alice,
+        &bob,
+        &vec![&env, 3],
+        &100,
+        &vec![&env, 5],
+        &0,
+    );
+    assert_eq!(trade_id, 1);
+    client.accept_trade(&game_id, &trade_id, &bob);
+
+    assert_eq!(client.get_property_owner(&game_id, &3), Some(bob.clone()));
+    assert_eq!(client.get_property_owner(&game_id, &5), Some(alice.clone()));
+    assert_eq!(client.get_properties(&game_id, &alice), vec![&env, 1, 5]);
+    assert_eq!(client.get_properties(&game_id, &bob), vec![&env, 3]);
+    assert_eq!(client.get_cash(&game_id, &alice), 1400);
+    assert_eq!(client.get_cash(&game_id, &bob), 1600);
+    assert_eq!(client.get_trade(&game_id, &trade_id), None);
+}
+
+#[test]
+fn test_counter_trade_replaces_offer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let alice = turn_order.get(0).unwrap();
+    let bob = turn_order.get(1).unwrap();
+    give_properties(&env, &contract_id, game_id, &bob, &[5]);
+
+    let trade_id = client.propose_trade(
+        &game_id,
+        &alice,
+        &bob,
+        &Vec::new(&env),
+        &100,
+        &vec![&env, 5],
+        &0,
+    );
+    let counter_id = client.counter_trade(
+        &game_id,
+        &trade_id,
+        &bob,
+        &vec![&env, 5],
+        &0,
+        &Vec::new(&env),
+        &250,
+    );
+
+    assert_eq!(client.get_trade(&game_id, &trade_id), None);
+    let counter = client.get_trade(&game_id, &counter_id).unwrap();
+    assert_eq!(counter.proposer, bob);
+    assert_eq!(counter.counterparty, alice.clone());
+
+    client.accept_trade(&game_id, &counter_id, &alice);
+    assert_eq!(client.get_property_owner(&game_id, &5), Some(alice));
+    assert_eq!(client.get_cash(&game_id, &bob), 1750);
+}
+
+#[test]
+fn test_reject_trade_removes_offer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, game_id, turn_order) = start_board_game(&env);
+    let alice = turn_order.get(0).unwrap();
+    let bob = turn_order.get(1).unwrap();
+
+    let trade_id = client.propose_trade(
+        &game_id,
+        &alice,
+        &bob,
+        &Vec::new(&env),
+        &100,
+        &Vec::new(&env),
+        &0,
+    );
+    client.reject_trade(&game_id, &trade_id, &bob);
+
+    assert_eq!(client.get_trade(&game_id, &trade_id), None);
+    assert_eq!(client.get_cash(&game_id, &alice), 1500);
+}
+
+#[test]
+#[should_panic(expected = "Trade offer expired")]
+fn test_accept_trade_after_turn_advances_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, controller, game_id, turn_order) = start_board_game(&env);
+    let alice = turn_order.get(0).unwrap();
+    let bob = turn_order.get(1).unwrap();
+
+    let trade_id = client.propose_trade(
+        &game_id,
+        &alice,
+        &bob,
+        &Vec::new(&env),
+        &100,
+        &Vec::new(&env),
+        &0,
+    );
+    roll_and_move(&env, &client, &controller, game_id, &alice, 7);
+    client.end_turn(&game_id, &alice);
+    client.accept_trade(&game_id, &trade_id, &bob);
+}
+
+#[test]
+#[should_panic(expected = "Property not owned by proposer")]
+fn test_propose_trade_with_unowned_property_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, game_id, turn_order) = start_board_game(&env);
+
+    client.propose_trade(
+        &game_id,
+        &turn_order.get(0).unwrap(),
+        &turn_order.get(1).unwrap(),
+        &vec![&env, 1],
+        &0,
+        &Vec::new(&env),
+        &0,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Trade is empty")]
+fn test_propose_empty_trade_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, game_id, turn_order) = start_board_game(&env);
+
+    client.propose_trade(
+        &game_id,
+        &turn_order.get(0).unwrap(),
+        &turn_order.get(1).unwrap(),
+        &Vec::new(&env),
+        &0,
+        &Vec::new(&env),
+        &0,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Not the trade counterparty")]
+fn test_proposer_cannot_accept_own_trade() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, game_id, turn_order) = start_board_game(&env);
+    let alice = turn_order.get(0).unwrap();
+
+    let trade_id = client.propose_trade(
+        &game_id,
+        &alice,
+        &turn_order.get(1).unwrap(),
+        &Vec::new(&env),
+        &100,
+        &Vec::new(&env),
+        &0,
+    );
+    client.accept_trade(&game_id, &trade_id, &alice);
+}
+
+#[test]
+#[should_panic(expected = "Property not owned by counterparty")]
+fn test_accept_trade_after_property_moved_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let alice = turn_order.get(0).unwrap();
+    let bob = turn_order.get(1).unwrap();
+    give_properties(&env, &contract_id, game_id, &bob, &[5]);
+
+    let first = client.propose_trade(
+        &game_id,
+        &alice,
+        &bob,
+        &Vec::new(&env),
+        &100,
+        &vec![&env, 5],
+        &0,
+    );
+    let second = client.propose_trade(
+        &game_id,
+        &alice,
+        &bob,
+        &Vec::new(&env),
+        &200,
+        &vec![&env, 5],
+        &0,
+    );
+    client.accept_trade(&game_id, &second, &bob);
+    client.accept_trade(&game_id, &first, &bob);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient cash")]
+fn test_accept_trade_without_cash_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, game_id, turn_order) = start_board_game(&env);
+
+    let trade_id = client.propose_trade(
+        &game_id,
+        &turn_order.get(0).unwrap(),
+        &turn_order.get(1).unwrap(),
+        &Vec::new(&env),
+        &1_501,
+        &Vec::new(&env),
+        &0,
+    );
+    client.accept_trade(&game_id, &trade_id, &turn_order.get(1).unwrap());
+}
+
+// -----------------------------------------------------------------------
+// Houses, hotels and mortgages
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_developed_property_charges_multiplied_rent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, controller, game_id, turn_order) = start_board_game(&env);
+    let owner = turn_order.get(1).unwrap();
+    let payer = turn_order.get(0).unwrap();
+    give_properties(&env, &contract_id, game_id, &owner, &[1, 3]);
+
+    client.build_house(&game_id, &owner, &3);
+    client.build_house(&game_id, &owner, &1);
+    client.build_house(&game_id, &owner, &3);
+    assert_eq!(client.get_development(&game_id, &3), 2);
+    assert_eq!(client.get_cash(&game_id, &owner), 1350);
+
+    roll_and_move(&env, &client, &controller, game_id, &payer, 3);
+
+    // Base rent 4, two houses: x15
+    assert_eq!(client.get_cash(&game_id, &payer), 1440);
+    assert_eq!(client.get_cash(&game_id, &owner), 1410);
+}
+
+#[test]
+fn test_build_up_to_hotel() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let owner = turn_order.get(0).unwrap();
+    give_properties(&env, &contract_id, game_id, &owner, &[1, 3]);
+
+    for _ in 0..5 {
+        client.build_house(&game_id, &owner, &1);
+        client.build_house(&game_id, &owner, &3);
+    }
+
+    assert_eq!(client.get_development(&game_id, &1), 5);
+    assert_eq!(client.get_cash(&game_id, &owner), 1000);
+    assert_eq!(client.get_net_worth(&game_id, &owner), 1000 + 120 + 500);
+}
+
+#[test]
+#[should_panic(expected = "Property fully developed")]
+fn test_build_past_hotel_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let owner = turn_order.get(0).unwrap();
+    give_properties(&env, &contract_id, game_id, &owner, &[1, 3]);
+
+    for _ in 0..5 {
+        client.build_house(&game_id, &owner, &1);
+        client.build_house(&game_id, &owner, &3);
+    }
+    client.build_house(&game_id, &owner, &1);
+}
+
+#[test]
+#[should_panic(expected = "Color set not complete")]
+fn test_build_without_color_set_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let owner = turn_order.get(0).unwrap();
+    give_properties(&env, &contract_id, game_id, &owner, &[1]);
+
+    client.build_house(&game_id, &owner, &1);
+}
+
+#[test]
+#[should_panic(expected = "Must build evenly across the color set")]
+fn test_uneven_build_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let owner = turn_order.get(0).unwrap();
+    give_properties(&env, &contract_id, game_id, &owner, &[1, 3]);
+
+    client.build_house(&game_id, &owner, &1);
+    client.build_house(&game_id, &owner, &1);
+}
+
+#[test]
+#[should_panic(expected = "Square cannot be developed")]
+fn test_build_on_railroad_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let owner = turn_order.get(0).unwrap();
+    give_properties(&env, &contract_id, game_id, &owner, &[5, 15, 25, 35]);
+
+    client.build_house(&game_id, &owner, &5);
+}
+
+#[test]
+fn test_sell_house_refunds_half_cost() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let owner = turn_order.get(0).unwrap();
+    give_properties(&env, &contract_id, game_id, &owner, &[1, 3]);
+
+    client.build_house(&game_id, &owner, &1);
+    client.sell_house(&game_id, &owner, &1);
+
+    assert_eq!(client.get_development(&game_id, &1), 0);
+    assert_eq!(client.get_cash(&game_id, &owner), 1475);
+}
+
+#[test]
+#[should_panic(expected = "Must sell evenly across the color set")]
+fn test_uneven_sell_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let owner = turn_order.get(0).unwrap();
+    give_properties(&env, &contract_id, game_id, &owner, &[1, 3]);
+
+    client.build_house(&game_id, &owner, &1);
+    client.build_house(&game_id, &owner, &3);
+    client.build_house(&game_id, &owner, &1);
+    client.sell_house(&game_id, &owner, &3);
+}
+
+#[test]
+fn test_mortgage_and_unmortgage_with_interest() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let owner = turn_order.get(0).unwrap();
+    give_properties(&env, &contract_id, game_id, &owner, &[39]);
+
+    client.mortgage_property(&game_id, &owner, &39);
+    assert!(client.is_mortgaged(&game_id, &39));
+    assert_eq!(client.get_cash(&game_id, &owner), 1700);
+    assert_eq!(client.get_net_worth(&game_id, &owner), 1700 + 200);
+
+    client.unmortgage_property(&game_id, &owner, &39);
+    assert!(!client.is_mortgaged(&game_id, &39));
+    assert_eq!(client.get_cash(&game_id, &owner), 1480);
+}
+
+#[test]
+fn test_mortgaged_property_collects_no_rent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, controller, game_id, turn_order) = start_board_game(&env);
+    let owner = turn_order.get(1).unwrap();
+    let payer = turn_order.get(0).unwrap();
+    give_properties(&env, &contract_id, game_id, &owner, &[5]);
+
+    client.mortgage_property(&game_id, &owner, &5);
+    roll_and_move(&env, &client, &controller, game_id, &payer, 5);
+
+    assert_eq!(client.get_cash(&game_id, &payer), 1500);
+}
+
+#[test]
+#[should_panic(expected = "Color set has a mortgaged property")]
+fn test_build_with_mortgaged_set_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let owner = turn_order.get(0).unwrap();
+    give_properties(&env, &contract_id, game_id, &owner, &[1, 3]);
+
+    client.mortgage_property(&game_id, &owner, &3);
+    client.build_house(&game_id, &owner, &1);
+}
+
+#[test]
+#[should_panic(expected = "Sell houses before mortgaging")]
+fn test_mortgage_developed_set_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let owner = turn_order.get(0).unwrap();
+    give_properties(&env, &contract_id, game_id, &owner, &[1, 3]);
+
+    client.build_house(&game_id, &owner, &1);
+    client.mortgage_property(&game_id, &owner, &3);
+}
+
+#[test]
+#[should_panic(expected = "Not the property owner")]
+fn test_mortgage_unowned_property_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, game_id, turn_order) = start_board_game(&env);
+
+    client.mortgage_property(&game_id, &turn_order.get(0).unwrap(), &1);
+}
+
+#[test]
+#[should_panic(expected = "Sell houses before trading")]
+fn test_trade_developed_property_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let alice = turn_order.get(0).unwrap();
+    let bob = turn_order.get(1).unwrap();
+    give_properties(&env, &contract_id, game_id, &alice, &[1, 3]);
+
+    let trade_id = client.propose_trade(
+        &game_id,
+        &alice,
+        &bob,
+        &vec![&env, 1],
+        &0,
+        &Vec::new(&env),
+        &100,
+    );
+    client.build_house(&game_id, &alice, &1);
+    client.accept_trade(&game_id, &trade_id, &bob);
+}
+
+#[test]
+#[should_panic(expected = "Sell houses before trading")]
+fn test_trade_property_in_developed_set_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let alice = turn_order.get(0).unwrap();
+    let bob = turn_order.get(1).unwrap();
+    give_properties(&env, &contract_id, game_id, &alice, &[1, 3]);
+    client.build_house(&game_id, &alice, &1);
+
+    let trade_id = client.propose_trade(
+        &game_id,
+        &alice,
+        &bob,
+        &vec![&env, 3],
+        &0,
+        &Vec::new(&env),
+        &100,
+    );
+    client.accept_trade(&game_id, &trade_id, &bob);
+}
+
+// -----------------------------------------------------------------------
+// Bankruptcy
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_rent_bankruptcy_transfers_assets_to_creditor() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    let controller = Address::generate(&env);
+    client.set_backend_game_controller(&controller);
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 2, 0);
+    let debtor = turn_order.get(0).unwrap();
+    let creditor = turn_order.get(1).unwrap();
+    give_properties(&env, &contract_id, game_id, &creditor, &[5, 15, 25, 35]);
+    give_properties(&env, &contract_id, game_id, &debtor, &[1]);
+    env.as_contract(&contract_id, || {
+        storage::set_cash(&env, game_id, &debtor, 100)
+    });
+
+    // Rent 200 > 100 cash + 30 from mortgaging square 1
+    roll_and_move(&env, &client, &controller, game_id, &debtor, 5);
+
+    assert_eq!(client.get_cash(&game_id, &debtor), 0);
+    assert_eq!(client.get_cash(&game_id, &creditor), 1630);
+    assert_eq!(
+        client.get_property_owner(&game_id, &1),
+        Some(creditor.clone())
+    );
+    assert!(client.is_mortgaged(&game_id, &1));
+    assert_eq!(client.get_eliminated(&game_id), vec![&env, debtor.clone()]);
+    assert!(!client.get_turn_order(&game_id).unwrap().contains(&debtor));
+    assert_eq!(
+        client.get_game(&game_id).unwrap().status,
+        GameStatus::Ongoing
+    );
+}
+
+#[test]
+fn test_tax_bankruptcy_returns_properties_to_bank_for_auction() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    let controller = Address::generate(&env);
+    client.set_backend_game_controller(&controller);
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 2, 0);
+    let debtor = turn_order.get(0).unwrap();
+    give_properties(&env, &contract_id, game_id, &debtor, &[1]);
+    env.as_contract(&contract_id, || {
+        storage::set_cash(&env, game_id, &debtor, 100)
+    });
+
+    // Income tax 200 > 100 cash + 30 from mortgaging square 1
+    roll_and_move(&env, &client, &controller, game_id, &debtor, 4);
+
+    assert_eq!(client.get_property_owner(&game_id, &1), None);
+    assert!(!client.is_mortgaged(&game_id, &1));
+    assert_eq!(client.get_properties(&game_id, &debtor), Vec::new(&env));
+    let auction = client.get_auction(&game_id, &1).unwrap();
+    assert_eq!(auction.excluded, None);
+    assert_eq!(client.get_eliminated(&game_id), vec![&env, debtor]);
+}
+
+#[test]
+fn test_bankruptcy_of_last_opponent_ends_game() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, controller, game_id, turn_order) = start_board_game(&env);
+    let debtor = turn_order.get(0).unwrap();
+    let creditor = turn_order.get(1).unwrap();
+    give_properties(&env, &contract_id, game_id, &creditor, &[5, 15, 25, 35]);
+    env.as_contract(&contract_id, || {
+        storage::set_cash(&env, game_id, &debtor, 100)
+    });
+
+    roll_and_move(&env, &client, &controller, game_id, &debtor, 5);
+
+    let game = client.get_game(&game_id).unwrap();
+    assert_eq!(game.status, GameStatus::Ended);
+    assert_eq!(game.winner, Some(creditor.clone()));
+    assert_eq!(
+        client.get_placements(&game_id).unwrap(),
+        vec![&env, creditor, debtor]
+    );
+}
+
+#[test]
+fn test_short_player_sells_houses_before_bankruptcy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, controller, game_id, turn_order) = start_board_game(&env);
+    let debtor = turn_order.get(0).unwrap();
+    let creditor = turn_order.get(1).unwrap();
+    give_properties(&env, &contract_id, game_id, &creditor, &[5, 15, 25, 35]);
+    give_properties(&env, &contract_id, game_id, &debtor, &[1, 3]);
+    for _ in 0..2 {
+        client.build_house(&game_id, &debtor, &1);
+        client.build_house(&game_id, &debtor, &3);
+    }
+    env.as_contract(&contract_id, || {
+        storage::set_cash(&env, game_id, &debtor, 100)
+    });
+
+    // Rent 200: 100 cash + 4 houses sold for 25 each covers it
+    roll_and_move(&env, &client, &controller, game_id, &debtor, 5);
+
+    assert_eq!(client.get_cash(&game_id, &debtor), 0);
+    assert_eq!(client.get_development(&game_id, &1), 0);
+    assert_eq!(client.get_development(&game_id, &3), 0);
+    assert!(!client.is_mortgaged(&game_id, &1));
+    assert_eq!(client.get_eliminated(&game_id), Vec::new(&env));
+}
+
+#[test]
+fn test_short_player_mortgages_before_bankruptcy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, controller, game_id, turn_order) = start_board_game(&env);
+    let debtor = turn_order.get(0).unwrap();
+    let creditor = turn_order.get(1).unwrap();
+    give_properties(&env, &contract_id, game_id, &creditor, &[5, 15, 25, 35]);
+    give_properties(&env, &contract_id, game_id, &debtor, &[39, 1]);
+    env.as_contract(&contract_id, || {
+        storage::set_cash(&env, game_id, &debtor, 100)
+    });
+
+    roll_and_move(&env, &client, &controller, game_id, &debtor, 5);
+
+    assert!(client.is_mortgaged(&game_id, &39));
+    assert!(!client.is_mortgaged(&game_id, &1));
+    assert_eq!(client.get_cash(&game_id, &debtor), 100);
+    assert_eq!(client.get_cash(&game_id, &creditor), 1700);
+}
+
+// -----------------------------------------------------------------------
+// Jail
+// -----------------------------------------------------------------------
+
+/// Minimal stand-in for tycoon-collectibles' perk lookup and burn.
+/// Records the perk of each token and which tokens were burned.
+#[soroban_sdk::contract]
+pub struct MockCollectibles;
+
+#[soroban_sdk::contractimpl]
+impl MockCollectibles {
+    pub fn set_perk(env: Env, token_id: u128, perk: u32) {
+        env.storage().persistent().set(&token_id, &perk);
+    }
+
+    pub fn get_token_perk(env: Env, token_id: u128) -> u32 {
+        env.storage().persistent().get(&token_id).unwrap_or(0)
+    }
+
+    pub fn burn_collectible_for_perk(env: Env, caller: Address, token_id: u128) {
+        caller.require_auth();
+        env.storage().persistent().set(&(caller, token_id), &true);
+    }
+
+    pub fn burned(env: Env, caller: Address, token_id: u128) -> bool {
+        env.storage().persistent().has(&(caller, token_id))
+    }
+}
+
+/// Puts `player` in jail directly in storage.
+fn jail_player(env: &Env, contract_id: &Address, game_id: u64, player: &Address) {
+    env.as_contract(contract_id, || {
+        storage::set_position(env, game_id, player, 10);
+        storage::set_jail_attempts(env, game_id, player, 0);
+    });
+}
+
+#[test]
+fn test_landing_on_go_to_jail_sends_player_to_jail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, controller, game_id, turn_order) = start_board_game(&env);
+    let player = turn_order.get(0).unwrap();
+    env.as_contract(&contract_id, || {
+        storage::set_position(&env, game_id, &player, 25)
+    });
+
+    roll_and_move(&env, &client, &controller, game_id, &player, 5);
+
+    assert_eq!(client.get_position(&game_id, &player), 10);
+    assert_eq!(client.get_jail_attempts(&game_id, &player), Some(0));
+}
+
+#[test]
+fn test_jailed_player_without_doubles_stays_in_jail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, controller, game_id, turn_order) = start_board_game(&env);
+    let player = turn_order.get(0).unwrap();
+    jail_player(&env, &contract_id, game_id, &player);
+
+    roll_and_move(&env, &client, &controller, game_id, &player, 7);
+
+    assert_eq!(client.get_position(&game_id, &player), 10);
+    assert_eq!(client.get_jail_attempts(&game_id, &player), Some(1));
+    assert!(client.get_turn_state(&game_id).unwrap().moved);
+}
+
+#[test]
+fn test_jailed_player_rolling_doubles_leaves_and_moves() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, controller, game_id, turn_order) = start_board_game(&env);
+    let player = turn_order.get(0).unwrap();
+    jail_player(&env, &contract_id, game_id, &player);
+
+    roll_dice_where(&env, &client, &controller, game_id, &player, |d1, d2| {
+        d1 == d2 && d1 == 1
+    });
+    client.move_token(&game_id, &player);
+
+    assert_eq!(client.get_jail_attempts(&game_id, &player), None);
+    assert_eq!(client.get_position(&game_id, &player), 12);
+}
+
+#[test]
+fn test_third_failed_attempt_forces_bail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, controller, game_id, turn_order) = start_board_game(&env);
+    let player = turn_order.get(0).unwrap();
+    let other = turn_order.get(1).unwrap();
+    jail_player(&env, &contract_id, game_id, &player);
+
+    for _ in 0..2 {
+        roll_and_move(&env, &client, &controller, game_id, &player, 7);
+        client.end_turn(&game_id, &player);
+        roll_and_move(&env, &client, &controller, game_id, &other, 7);
+        client.end_turn(&game_id, &other);
+    }
+    roll_and_move(&env, &client, &controller, game_id, &player, 7);
+
+    assert_eq!(client.get_jail_attempts(&game_id, &player), None);
+    assert_eq!(client.get_position(&game_id, &player), 17);
+    assert_eq!(client.get_cash(&game_id, &player), 1450);
+}
+
+#[test]
+fn test_pay_bail_releases_before_moving() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, controller, game_id, turn_order) = start_board_game(&env);
+    let player = turn_order.get(0).unwrap();
+    jail_player(&env, &contract_id, game_id, &player);
+
+    client.pay_bail(&game_id, &player);
+    assert_eq!(client.get_jail_attempts(&game_id, &player), None);
+    assert_eq!(client.get_cash(&game_id, &player), 1450);
+
+    roll_and_move(&env, &client, &controller, game_id, &player, 7);
+    assert_eq!(client.get_position(&game_id, &player), 17);
+}
+
+#[test]
+#[should_panic(expected = "Player is not in jail")]
+fn test_pay_bail_outside_jail_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, game_id, turn_order) = start_board_game(&env);
+
+    client.pay_bail(&game_id, &turn_order.get(0).unwrap());
+}
+
+#[test]
+fn test_jail_free_perk_is_burned_to_leave_jail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let player = turn_order.get(0).unwrap();
+    let collectibles = MockCollectiblesClient::new(&env, &env.register(MockCollectibles, ()));
+    collectibles.set_perk(&7, &6);
+    client.set_collectibles_contract(&collectibles.address);
+    jail_player(&env, &contract_id, game_id, &player);
+
+    client.use_jail_free_perk(&game_id, &player, &7);
+
+    assert_eq!(client.get_jail_attempts(&game_id, &player), None);
+    assert!(collectibles.burned(&player, &7));
+    assert_eq!(client.get_cash(&game_id, &player), 1500);
+}
+
+#[test]
+#[should_panic(expected = "Collectible is not a JailFree perk")]
+fn test_other_perk_cannot_leave_jail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let player = turn_order.get(0).unwrap();
+    let collectibles = MockCollectiblesClient::new(&env, &env.register(MockCollectibles, ()));
+    collectibles.set_perk(&7, &5);
+    client.set_collectibles_contract(&collectibles.address);
+    jail_player(&env, &contract_id, game_id, &player);
+
+    client.use_jail_free_perk(&game_id, &player, &7);
+}
+
+#[test]
+#[should_panic(expected = "Collectibles contract not set")]
+fn test_jail_free_perk_without_collectibles_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, _, game_id, turn_order) = start_board_game(&env);
+    let player = turn_order.get(0).unwrap();
+    jail_player(&env, &contract_id, game_id, &player);
+
+    client.use_jail_free_perk(&game_id, &player, &7);
+}
+
+#[test]
+fn test_jailed_player_forfeit_uses_up_attempt() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, controller, game_id, turn_order) = start_board_game(&env);
+    let player = turn_order.get(0).unwrap();
+    jail_player(&env, &contract_id, game_id, &player);
+
+    let seed = BytesN::from_array(&env, &[1u8; 32]);
+    client.commit_dice(&game_id, &player, &dice_commitment(&env, &seed));
+    client.commit_dice(&game_id, &controller, &dice_commitment(&env, &seed));
+    client.reveal_dice(&game_id, &controller, &seed);
+    env.ledger().set_timestamp(1_300);
+    client.resolve_dice_timeout(&game_id);
+
+    assert_eq!(client.get_jail_attempts(&game_id, &player), Some(1));
+}
+
+#[test]
+fn test_timed_out_player_properties_return_to_bank() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    let controller = Address::generate(&env);
+    client.set_backend_game_controller(&controller);
+    let (game_id, turn_order) = start_timed_game(&env, &client, &contract_id, &usdc_token, 2, 60);
+    let idle = turn_order.get(0).unwrap();
+    let next = turn_order.get(1).unwrap();
+    give_properties(&env, &contract_id, game_id, &idle, &[5, 15, 25, 35]);
+    env.as_contract(&contract_id, || {
+        storage::set_mortgaged(&env, game_id, 15, true)
+    });
+
+    env.ledger().set_timestamp(1_100);
+    client.claim_timeout(&game_id, &next);
+
+    assert_eq!(client.get_property_owner(&game_id, &5), None);
+    assert!(!client.is_mortgaged(&game_id, &15));
+    assert_eq!(client.get_properties(&game_id, &idle), Vec::new(&env));
+    assert_eq!(client.get_cash(&game_id, &idle), 0);
+
+    // No rent is owed to the eliminated player
+    roll_and_move(&env, &client, &controller, game_id, &next, 5);
+    assert_eq!(client.get_cash(&game_id, &next), 1500);
+}
+
+#[test]
+fn test_eliminated_bidder_leading_bid_is_voided() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, game_id, turn_order) = open_railroad_auction(&env);
+    let decliner = turn_order.get(0).unwrap();
+    let bidder = turn_order.get(1).unwrap();
+    let rival = turn_order.get(2).unwrap();
+
+    client.place_bid(&game_id, &5, &bidder, &100);
+    client.end_turn(&game_id, &decliner);
+
+    // The leading bidder times out on their turn
+    env.ledger().set_timestamp(1_100);
+    client.claim_timeout(&game_id, &rival);
+
+    let auction = client.get_auction(&game_id, &5).unwrap();
+    assert_eq!(auction.highest_bidder, None);
+    assert_eq!(auction.highest_bid, 0);
+
+    client.place_bid(&game_id, &5, &rival, &10);
+    env.ledger().set_timestamp(1_300);
+    client.settle_auction(&game_id, &5);
+    assert_eq!(client.get_property_owner(&game_id, &5), Some(rival));
+}